@@ -0,0 +1,88 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, Ident, Path};
+
+struct Options {
+    parser: Path,
+    policy: Vec<Ident>,
+}
+
+impl Options {
+    fn from_input(input: &DeriveInput) -> syn::Result<Self> {
+        let mut options = Options {
+            parser: Ident::new("parse", Span::call_site()).into(),
+            policy: Vec::new(),
+        };
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("nom")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("parser") {
+                    options.parser = meta.value()?.parse()?;
+                    return Ok(());
+                }
+
+                for policy in ["trim", "crlf", "partial"] {
+                    if meta.path.is_ident(policy) {
+                        options.policy.push(Ident::new(policy, Span::call_site()));
+                        return Ok(());
+                    }
+                }
+
+                Err(meta.error("expected `parser = <fn>`, `trim`, `crlf` or `partial`"))
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Implements `FromStr` and `TryFrom<&str>` on top of a nom parser of the type.
+///
+/// The parser defaults to `Self::parse`. `#[nom(parser = name)]` picks another associated
+/// function or, given a longer path, a free function. The whole input has to be consumed
+/// unless relaxed with the `trim`, `crlf` and `partial` policy flags.
+#[proc_macro_derive(FromStrNom, attributes(nom))]
+pub fn derive_from_str_nom(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let options = Options::from_input(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let parser = match options.parser.get_ident() {
+        Some(ident) => quote! { Self::#ident },
+        None => {
+            let path = &options.parser;
+            quote! { #path }
+        }
+    };
+
+    let policy = &options.policy;
+
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::aoc2022::ParseError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                let policy = ::aoc2022::Policy::strict()#(.#policy())*;
+                policy.parse(s, #parser)
+            }
+        }
+
+        impl #impl_generics ::std::convert::TryFrom<&str> for #name #ty_generics #where_clause {
+            type Error = ::aoc2022::ParseError;
+
+            fn try_from(s: &str) -> ::std::result::Result<Self, Self::Error> {
+                <Self as ::std::str::FromStr>::from_str(s)
+            }
+        }
+    })
+}
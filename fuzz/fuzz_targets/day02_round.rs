@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use aoc2022::day02::Round;
+use aoc2022::fuzzing::check_parse;
+
+fuzz_target!(|data: &str| {
+    check_parse::<Round>(data);
+});
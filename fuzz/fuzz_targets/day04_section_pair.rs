@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use aoc2022::day04::SectionPair;
+use aoc2022::fuzzing::check_round_trip;
+
+fuzz_target!(|data: &str| {
+    check_round_trip::<SectionPair>(data);
+});
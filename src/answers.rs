@@ -0,0 +1,163 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+// Answers are kept as `[dayNN]` tables with `partN` keys, holding integers or strings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(usize, usize), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).with_context(|| format!("error parsing {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e).with_context(|| format!("error reading {}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let table = text.parse::<toml::Table>()?;
+        let mut answers = Answers::default();
+
+        for (day_key, parts) in table {
+            let day = parse_key(&day_key, "day")?;
+            let parts = parts.as_table().with_context(|| format!("`{}` is not a table", day_key))?;
+
+            for (part_key, value) in parts {
+                let part = parse_key(part_key, "part")?;
+                let answer = match value {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(i) => i.to_string(),
+                    _ => bail!("answer for {}.{} must be a string or an integer", day_key, part_key),
+                };
+                answers.insert(day, part, &answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string()).with_context(|| format!("error writing {}", path.display()))
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        self.entries.get(&(day, part)).map(|s| s.as_str())
+    }
+
+    pub fn insert(&mut self, day: usize, part: usize, answer: &str) {
+        self.entries.insert((day, part), answer.to_string());
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.entries.keys().copied()
+    }
+
+    pub fn check(&self, day: usize, part: usize, answer: &str) -> Check {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Mismatch { expected: expected.to_string() },
+            None => Check::New,
+        }
+    }
+}
+
+pub(crate) fn parse_key(key: &str, prefix: &str) -> Result<usize> {
+    key.strip_prefix(prefix)
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| anyhow!("expected a key like `{}1`, got `{}`", prefix, key))
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut current = None;
+
+        for (&(day, part), answer) in &self.entries {
+            if current != Some(day) {
+                if current.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{:02}]", day)?;
+                current = Some(day);
+            }
+
+            // only answers that read back unchanged are written as bare integers, "007" stays a string
+            match answer.parse::<i64>() {
+                Ok(i) if i.to_string() == *answer => writeln!(f, "part{} = {}", part, i)?,
+                _ => writeln!(f, "part{} = {:?}", part, answer)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Mismatch { expected: String },
+    New,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "[day01]\npart1 = 24000\npart2 = 45000\n\n[day05]\npart1 = \"CMZ\"\n";
+
+    #[test]
+    fn answers_round_trip_through_toml() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 2), Some("45000"));
+        assert_eq!(answers.get(5, 1), Some("CMZ"));
+        assert_eq!(answers.get(5, 2), None);
+        assert_eq!(answers.to_string(), ANSWERS);
+    }
+
+    #[test]
+    fn answers_that_only_look_like_integers_keep_their_text() {
+        let mut answers = Answers::default();
+        answers.insert(9, 1, "007");
+        answers.insert(9, 2, "+5");
+        answers.insert(10, 1, "-12");
+
+        let text = answers.to_string();
+        assert!(text.contains("part1 = \"007\"") && text.contains("part1 = -12"));
+
+        let reloaded = Answers::parse(&text).unwrap();
+        assert_eq!(reloaded, answers);
+        assert_eq!(reloaded.check(9, 1, "007"), Check::Correct);
+    }
+
+    #[test]
+    fn checks_compare_against_recorded_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, 1, "24000"), Check::Correct);
+        assert_eq!(answers.check(1, 1, "24001"), Check::Mismatch { expected: "24000".to_string() });
+        assert_eq!(answers.check(2, 1, "15"), Check::New);
+    }
+
+    #[test]
+    fn malformed_registries_are_rejected() {
+        assert!(Answers::parse("[dayone]\npart1 = 1\n").is_err());
+        assert!(Answers::parse("[day01]\nfirst = 1\n").is_err());
+        assert!(Answers::parse("[day01]\npart1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn missing_registry_is_empty() {
+        let answers = Answers::load(Path::new("does/not/exist.toml")).unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+
+use crate::answers::parse_key;
+use crate::{InputSource, Puzzle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options { warmup: 3, iterations: 20 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort_unstable();
+
+        // nearest-rank percentiles
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Stats { min: samples[0], median: rank(50), p95: rank(95) }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Read,
+    Parse,
+    Solve,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Read, Stage::Parse, Stage::Solve];
+
+    fn key(&self) -> &'static str {
+        match self {
+            Stage::Read => "read",
+            Stage::Parse => "parse",
+            Stage::Solve => "solve",
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: usize,
+    pub part: usize,
+    pub read: Stats,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Measurement {
+    pub fn stage(&self, stage: Stage) -> &Stats {
+        match stage {
+            Stage::Read => &self.read,
+            Stage::Parse => &self.parse,
+            Stage::Solve => &self.solve,
+        }
+    }
+}
+
+pub fn bench(puzzle: &dyn Puzzle, source: &InputSource, options: Options) -> Result<Measurement> {
+    let iterations = options.iterations.max(1);
+    let mut read = Vec::with_capacity(iterations);
+    let mut parse = Vec::with_capacity(iterations);
+    let mut solve = Vec::with_capacity(iterations);
+
+    for i in 0..options.warmup + iterations {
+        let start = Instant::now();
+        let lines = source.read().context("error reading input")?;
+        let elapsed = start.elapsed();
+
+        let timed = puzzle.run_timed(lines)?;
+        if i >= options.warmup {
+            read.push(elapsed);
+            parse.push(timed.parse);
+            solve.push(timed.solve);
+        }
+    }
+
+    Ok(Measurement {
+        day: puzzle.day(),
+        part: puzzle.part(),
+        read: Stats::from_samples(read),
+        parse: Stats::from_samples(parse),
+        solve: Stats::from_samples(solve),
+    })
+}
+
+// Baselines keep the median of every stage in nanoseconds, as `[dayNN.partN]` tables.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(usize, usize, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).with_context(|| format!("error reading {}", path.display()))?;
+        Baseline::parse(&text).with_context(|| format!("error parsing {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let table = text.parse::<toml::Table>()?;
+        let mut baseline = Baseline::default();
+
+        for (day_key, parts) in &table {
+            let day = parse_key(day_key, "day")?;
+            for (part_key, stages) in parts.as_table().context("expected a table of parts")? {
+                let part = parse_key(part_key, "part")?;
+                let stages = stages.as_table().context("expected a table of stages")?;
+
+                for stage in Stage::ALL {
+                    if let Some(nanos) = stages.get(stage.key()).and_then(|v| v.as_integer()) {
+                        let nanos = u64::try_from(nanos).with_context(|| {
+                            format!("malformed baseline: {}.{}.{} = {}", day_key, part_key, stage, nanos)
+                        })?;
+                        baseline.medians.insert((day, part, stage), Duration::from_nanos(nanos));
+                    }
+                }
+            }
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string()).with_context(|| format!("error writing {}", path.display()))
+    }
+
+    pub fn record(&mut self, m: &Measurement) {
+        for stage in Stage::ALL {
+            self.medians.insert((m.day, m.part, stage), m.stage(stage).median);
+        }
+    }
+
+    pub fn get(&self, day: usize, part: usize, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, part, stage)).copied()
+    }
+
+    // Relative change of the median against the baseline, e.g. `0.1` for 10% slower.
+    pub fn compare(&self, m: &Measurement, stage: Stage) -> Option<f64> {
+        let base = self.get(m.day, m.part, stage)?.as_secs_f64();
+        if base == 0.0 {
+            return None;
+        }
+        Some(m.stage(stage).median.as_secs_f64() / base - 1.0)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut current = None;
+
+        for (&(day, part, stage), median) in &self.medians {
+            if current != Some((day, part)) {
+                if current.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{:02}.part{}]", day, part)?;
+                current = Some((day, part));
+            }
+            writeln!(f, "{} = {}", stage, median.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&s| Duration::from_millis(s)).collect()
+    }
+
+    fn measurement(read: u64, parse: u64, solve: u64) -> Measurement {
+        let stats = |v| Stats::from_samples(ms(&[v]));
+        Measurement { day: 1, part: 2, read: stats(read), parse: stats(parse), solve: stats(solve) }
+    }
+
+    #[test]
+    fn stats_use_nearest_rank() {
+        let stats = Stats::from_samples(ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+
+        let stats = Stats::from_samples(ms(&(1..=100).collect::<Vec<_>>()));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+
+        let stats = Stats::from_samples(ms(&[7]));
+        let seven = Duration::from_millis(7);
+        assert_eq!((stats.min, stats.median, stats.p95), (seven, seven, seven));
+    }
+
+    #[test]
+    fn baseline_round_trips_and_compares() {
+        let mut baseline = Baseline::default();
+        baseline.record(&measurement(10, 20, 40));
+
+        let text = baseline.to_string();
+        assert_eq!(text, "[day01.part2]\nread = 10000000\nparse = 20000000\nsolve = 40000000\n");
+        assert_eq!(Baseline::parse(&text).unwrap(), baseline);
+
+        let current = measurement(10, 10, 50);
+        assert_eq!(baseline.compare(&current, Stage::Read), Some(0.0));
+        assert_eq!(baseline.compare(&current, Stage::Parse), Some(-0.5));
+        assert_eq!(baseline.compare(&current, Stage::Solve), Some(0.25));
+    }
+
+    #[test]
+    fn negative_baselines_are_malformed() {
+        let error = Baseline::parse("[day01.part1]\nsolve = -5\n").unwrap_err();
+        assert_eq!(error.to_string(), "malformed baseline: day01.part1.solve = -5");
+    }
+
+    #[test]
+    fn benchmarks_run_every_stage() {
+        let puzzle = crate::find_puzzles(Some(1), Some(1))[0];
+        let options = Options { warmup: 1, iterations: 3 };
+        let m = bench(puzzle, &InputSource::day(1), options).unwrap();
+
+        assert_eq!((m.day, m.part), (1, 1));
+        assert!(m.read.min <= m.read.median && m.read.median <= m.read.p95);
+    }
+}
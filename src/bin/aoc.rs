@@ -0,0 +1,468 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Parser, Subcommand};
+
+use aoc2022::answers::{answers_path, Answers, Check};
+use aoc2022::bench::{bench, Baseline, Options, Stage};
+use aoc2022::extract::{examples_path, extract, EXAMPLE_VARIANT};
+use aoc2022::fetch::{fetch_input, Client, Fetched};
+use aoc2022::input::input_dir;
+use aoc2022::output::{write_table, Format, Record, Status, Timings};
+use aoc2022::parallel::{run_parallel, Job};
+use aoc2022::submit::{self, history_path, History, Verdict};
+use aoc2022::watch;
+use aoc2022::{find_puzzles, puzzles, Answer, InputSource, Puzzle};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 puzzle runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a single day or of all days
+    Run {
+        /// Day number or `all`
+        day: DaySelector,
+
+        /// Only run this part of the selected days
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the input from this file instead, `-` for stdin
+        #[arg(long, conflicts_with = "variant")]
+        input: Option<String>,
+
+        /// Read `dayNN.<VARIANT>.txt` instead of the puzzle input, e.g. `example`
+        #[arg(long)]
+        variant: Option<String>,
+
+        /// Output format: `plain`, `json` or `json-lines`
+        #[arg(long, default_value = "plain")]
+        format: Format,
+
+        /// Number of solutions to run at the same time
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+    },
+    /// Re-run a day and its tests whenever its source or inputs change
+    Watch {
+        day: usize,
+
+        /// Milliseconds between checks for changes
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Download the puzzle input of a day into the input directory
+    Fetch {
+        day: usize,
+
+        /// Server to download from, defaults to $AOC_BASE_URL or adventofcode.com
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Solve a part and submit its answer, unless the history rules it out
+    Submit {
+        day: usize,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Server to submit to, defaults to $AOC_BASE_URL or adventofcode.com
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Run every solution and compare the results with the recorded answers
+    Verify {
+        /// Answers file, defaults to answers.toml next to Cargo.toml
+        #[arg(long)]
+        answers: Option<PathBuf>,
+
+        /// Record answers that are not in the answers file yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Extract the example input and answers from a saved puzzle page
+    Extract {
+        day: usize,
+
+        /// Puzzle description saved from the browser
+        html: PathBuf,
+    },
+    /// Time reading, parsing and solving of a single day or of all days
+    Bench {
+        /// Day number or `all`
+        day: DaySelector,
+
+        /// Only benchmark this part of the selected days
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = Options::default().warmup)]
+        warmup: usize,
+
+        /// Timed runs per solution
+        #[arg(long, default_value_t = Options::default().iterations)]
+        iterations: usize,
+
+        /// Compare the medians with a saved baseline
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Save the medians as a baseline
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelector {
+    All,
+    Day(usize),
+}
+
+impl DaySelector {
+    fn day(&self) -> Option<usize> {
+        match self {
+            DaySelector::All => None,
+            DaySelector::Day(d) => Some(*d),
+        }
+    }
+}
+
+impl FromStr for DaySelector {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(DaySelector::All),
+            _ => s
+                .parse()
+                .map(DaySelector::Day)
+                .map_err(|_| anyhow!("expected a day number or `all`, got `{}`", s)),
+        }
+    }
+}
+
+struct SourceSelector {
+    input: Option<String>,
+    variant: Option<String>,
+}
+
+impl SourceSelector {
+    fn for_day(&self, day: usize) -> InputSource {
+        match (&self.input, &self.variant) {
+            (Some(input), _) => InputSource::from_arg(input),
+            (None, Some(variant)) => InputSource::variant(day, variant),
+            (None, None) => InputSource::day(day),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, part, input, variant, format, jobs } => {
+            let source = SourceSelector { input, variant };
+            run(day, part.map(usize::from), &source, format, jobs.into())
+        }
+        Command::Watch { day, interval } => watch_day(day, Duration::from_millis(interval)),
+        Command::Fetch { day, base_url } => fetch(day, base_url.as_deref()),
+        Command::Submit { day, part, base_url } => submit_answer(day, part.into(), base_url.as_deref()),
+        Command::Verify { answers, record } => verify(&answers.unwrap_or_else(answers_path), record),
+        Command::Extract { day, html } => extract_examples(day, &html),
+        Command::Bench { day, part, warmup, iterations, baseline, save_baseline } => {
+            let options = Options { warmup, iterations };
+            run_bench(day, part.map(usize::from), options, baseline.as_deref(), save_baseline.as_deref())
+        }
+    }
+}
+
+fn extract_examples(day: usize, html: &Path) -> Result<()> {
+    let html = fs::read_to_string(html).with_context(|| format!("error reading {}", html.display()))?;
+    let extracted = extract(&html);
+    if extracted.examples.is_empty() {
+        bail!("no example input found");
+    }
+
+    let dir = input_dir();
+    for (i, example) in extracted.examples.iter().enumerate() {
+        let variant = match i {
+            0 => EXAMPLE_VARIANT.to_string(),
+            _ => format!("{}{}", EXAMPLE_VARIANT, i + 1),
+        };
+
+        let path = InputSource::variant(day, &variant).resolve().context("error resolving example path")?;
+        fs::create_dir_all(&dir)?;
+        fs::write(&path, example).with_context(|| format!("error writing {}", path.display()))?;
+        println!("day {:02}: wrote {}", day, path.display());
+    }
+
+    let path = examples_path();
+    let mut answers = Answers::load(&path)?;
+    for (part, answer) in extracted.answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            answers.insert(day, part + 1, answer);
+            println!("day {:02} part {}: example answer {}", day, part + 1, answer);
+        }
+    }
+    answers.save(&path)?;
+
+    Ok(())
+}
+
+fn run_bench(
+    day: DaySelector,
+    part: Option<usize>,
+    options: Options,
+    baseline: Option<&Path>,
+    save_baseline: Option<&Path>,
+) -> Result<()> {
+    let puzzles = find_puzzles(day.day(), part);
+    if puzzles.is_empty() {
+        bail!("no solutions registered for the given selection");
+    }
+
+    let baseline = baseline.map(Baseline::load).transpose()?;
+    let mut saved = Baseline::default();
+
+    println!("{:<14} {:<6} {:>12} {:>12} {:>12} {:>10}", "solution", "stage", "min", "median", "p95", "baseline");
+    for puzzle in puzzles {
+        let m = bench(puzzle, &InputSource::day(puzzle.day()), options)
+            .with_context(|| format!("error benchmarking day {:02} part {}", puzzle.day(), puzzle.part()))?;
+
+        for stage in Stage::ALL {
+            let stats = m.stage(stage);
+            let change = match baseline.as_ref().and_then(|b| b.compare(&m, stage)) {
+                Some(change) => format!("{:+.1}%", change * 100.0),
+                None => "-".to_string(),
+            };
+
+            println!(
+                "{:<14} {:<6} {:>12} {:>12} {:>12} {:>10}",
+                format!("day {:02} part {}", m.day, m.part),
+                stage,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95),
+                change,
+            );
+        }
+
+        saved.record(&m);
+    }
+
+    if let Some(path) = save_baseline {
+        saved.save(path)?;
+    }
+
+    Ok(())
+}
+
+fn watch_day(day: usize, interval: Duration) -> Result<()> {
+    let mut files = watch::watched_files(day);
+    let mut seen = watch::snapshot(&files);
+    let mut previous = None;
+
+    println!("watching {} file(s) of day {:02}", files.len(), day);
+    loop {
+        match watch::evaluate(day) {
+            Ok(outcome) => {
+                for line in watch::diff(day, previous.as_ref(), &outcome) {
+                    println!("{}", line);
+                }
+                previous = Some(outcome);
+            }
+            Err(e) => eprintln!("day {:02}: {:#}", day, e),
+        }
+
+        let changed = loop {
+            thread::sleep(interval);
+            files = watch::watched_files(day);
+            let current = watch::snapshot(&files);
+            let changed = watch::changes(&seen, &current);
+            seen = current;
+
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+
+        for path in changed {
+            println!("changed: {}", path.display());
+        }
+    }
+}
+
+fn fetch(day: usize, base_url: Option<&str>) -> Result<()> {
+    let client = Client::from_env(base_url)?;
+
+    match fetch_input(&client, day, &input_dir())? {
+        Fetched::Cached(path) => println!("day {:02}: keeping existing {}", day, path.display()),
+        Fetched::Downloaded(path) => println!("day {:02}: downloaded to {}", day, path.display()),
+    }
+
+    Ok(())
+}
+
+fn submit_answer(day: usize, part: usize, base_url: Option<&str>) -> Result<()> {
+    let puzzle = *find_puzzles(Some(day), Some(part))
+        .first()
+        .with_context(|| format!("no solution registered for day {:02} part {}", day, part))?;
+    let (answer, _) = solve(puzzle, InputSource::day(day).read().context("error reading input")?)?;
+    let answer = answer.to_string();
+
+    let client = Client::from_env(base_url)?;
+    let path = history_path();
+    let mut history = History::load(&path)?;
+
+    let result = submit::submit(&client, &mut history, day, part, &answer, submit::now());
+    history.save(&path)?;
+    let verdict = result?;
+
+    println!("day {:02} part {}: {} is {}", day, part, answer, verdict);
+    match verdict {
+        Verdict::Right => {
+            let mut answers = Answers::load(&answers_path())?;
+            answers.insert(day, part, &answer);
+            answers.save(&answers_path())
+        }
+        Verdict::Unknown => bail!("unrecognised response from the server"),
+        _ => Ok(()),
+    }
+}
+
+fn run(day: DaySelector, part: Option<usize>, source: &SourceSelector, format: Format, jobs: usize) -> Result<()> {
+    let puzzles = find_puzzles(day.day(), part);
+    if puzzles.is_empty() {
+        bail!("no solutions registered for the given selection");
+    }
+
+    // inputs are read up front, stdin can't be shared between workers
+    let mut inputs = Inputs::default();
+    let mut records = vec![None; puzzles.len()];
+    let mut queued = Vec::new();
+
+    for (i, puzzle) in puzzles.into_iter().enumerate() {
+        match inputs.lines(&source.for_day(puzzle.day())) {
+            Ok(lines) => queued.push((i, Job { puzzle, lines })),
+            Err(e) => records[i] = Some(Record::error(puzzle.day(), puzzle.part(), &e)),
+        }
+    }
+
+    let (slots, queued): (Vec<_>, Vec<_>) = queued.into_iter().unzip();
+    let start = Instant::now();
+    for (i, record) in slots.into_iter().zip(run_parallel(queued, jobs)) {
+        records[i] = Some(record);
+    }
+    let elapsed = start.elapsed();
+
+    let records = records.into_iter().flatten().collect::<Vec<_>>();
+    let mut out = io::stdout().lock();
+
+    match (format, day) {
+        (Format::Plain, DaySelector::All) => {
+            write_table(&mut out, &records)?;
+            writeln!(out, "finished in {:.2?} with {} job(s)", elapsed, jobs)?;
+        }
+        (Format::Plain, _) => {
+            for record in &records {
+                match record.status {
+                    Status::Ok => writeln!(out, "{}", record)?,
+                    _ => eprintln!("{}", record),
+                }
+            }
+        }
+        _ => format.write(&mut out, &records)?,
+    }
+
+    let failed = records.iter().filter(|r| r.status != Status::Ok).count();
+    if failed > 0 {
+        bail!("{} solution(s) failed", failed);
+    }
+
+    Ok(())
+}
+
+fn verify(path: &Path, record: bool) -> Result<()> {
+    let mut answers = Answers::load(path)?;
+    let mut inputs = Inputs::default();
+    let mut recorded = false;
+    let mut problems = 0;
+
+    for puzzle in puzzles() {
+        let (day, part) = (puzzle.day(), puzzle.part());
+        let answer = match inputs.lines(&InputSource::day(day)).and_then(|l| solve(*puzzle, l)) {
+            Ok((answer, _)) => answer.to_string(),
+            Err(e) => {
+                println!("day {:02} part {}: failed: {:#}", day, part, e);
+                problems += 1;
+                continue;
+            }
+        };
+
+        match answers.check(day, part, &answer) {
+            Check::Correct => println!("day {:02} part {}: ok {}", day, part, answer),
+            Check::Mismatch { expected } => {
+                println!("day {:02} part {}: MISMATCH got {}, expected {}", day, part, answer, expected);
+                problems += 1;
+            }
+            Check::New if record => {
+                println!("day {:02} part {}: recorded {}", day, part, answer);
+                answers.insert(day, part, &answer);
+                recorded = true;
+            }
+            Check::New => println!("day {:02} part {}: new {}", day, part, answer),
+        }
+    }
+
+    for (day, part) in answers.keys() {
+        if find_puzzles(Some(day), Some(part)).is_empty() {
+            println!("day {:02} part {}: missing solution for recorded answer", day, part);
+            problems += 1;
+        }
+    }
+
+    if recorded {
+        answers.save(path)?;
+    }
+
+    if problems > 0 {
+        bail!("{} problem(s) found", problems);
+    }
+
+    Ok(())
+}
+
+// Both parts share an input, and stdin can only be consumed once.
+#[derive(Default)]
+struct Inputs {
+    cache: HashMap<InputSource, io::Result<Vec<String>>>,
+}
+
+impl Inputs {
+    fn lines(&mut self, source: &InputSource) -> Result<Vec<String>> {
+        let lines = self.cache.entry(source.clone()).or_insert_with(|| source.read());
+
+        match lines {
+            Ok(lines) => Ok(lines.clone()),
+            Err(e) => Err(anyhow!("{}", e)).context("error reading input"),
+        }
+    }
+}
+
+fn solve(puzzle: &dyn Puzzle, lines: Vec<String>) -> Result<(Answer, Timings)> {
+    let timed = puzzle.run_timed(lines)?;
+    Ok((timed.answer, Timings { parse: timed.parse, solve: timed.solve }))
+}
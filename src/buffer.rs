@@ -0,0 +1,124 @@
+use std::io::{self, Read};
+
+use nom::IResult;
+
+use crate::error::finish;
+use crate::{pack_lines, pack_offsets, InputSource, ParseError};
+
+pub trait ParseBorrowed<'a>: Sized {
+    fn parse_borrowed(s: &'a str) -> IResult<&'a str, Self>;
+
+    fn from_borrowed(s: &'a str) -> Result<Self, ParseError> {
+        finish(s, Self::parse_borrowed(s))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputBuffer {
+    text: String,
+}
+
+impl InputBuffer {
+    pub fn new(text: impl Into<String>) -> Self {
+        InputBuffer { text: text.into() }
+    }
+
+    pub fn load(source: &InputSource) -> Result<Self, io::Error> {
+        let mut text = String::new();
+        source.reader()?.read_to_string(&mut text)?;
+        Ok(InputBuffer { text })
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    pub fn packs(&self) -> Vec<Vec<&str>> {
+        pack_lines(self.lines())
+    }
+
+    pub fn parse_lines<'a, T: ParseBorrowed<'a>>(&'a self) -> Result<Vec<T>, ParseError> {
+        parse_lines(self.lines(), 0)
+    }
+
+    pub fn parse_packs<'a, T: ParseBorrowed<'a>>(&'a self) -> Result<Vec<Vec<T>>, ParseError> {
+        let packs = self.packs();
+
+        pack_offsets(&packs)
+            .into_iter()
+            .zip(packs)
+            .enumerate()
+            .map(|(i, (offset, p))| parse_lines(p.into_iter(), offset).map_err(|e| e.in_pack(i)))
+            .collect()
+    }
+}
+
+fn parse_lines<'a, T: ParseBorrowed<'a>>(
+    lines: impl Iterator<Item = &'a str>,
+    offset: usize,
+) -> Result<Vec<T>, ParseError> {
+    lines
+        .enumerate()
+        .map(|(i, l)| T::from_borrowed(l).map_err(|e| e.with_snippet(l).offset_lines(offset + i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::{alpha1, char};
+    use nom::combinator::{eof, map};
+    use nom::sequence::{separated_pair, terminated};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair<'a>(&'a str, &'a str);
+
+    impl<'a> ParseBorrowed<'a> for Pair<'a> {
+        fn parse_borrowed(s: &'a str) -> IResult<&'a str, Self> {
+            let parser = separated_pair(alpha1, char('-'), alpha1);
+            let mut parser = map(terminated(parser, eof), |(a, b)| Pair(a, b));
+            parser(s)
+        }
+    }
+
+    #[test]
+    fn parsed_values_borrow_from_the_buffer() {
+        let buffer = InputBuffer::new("ab-cd\nef-gh\n");
+        let pairs = buffer.parse_lines::<Pair>().unwrap();
+        assert_eq!(pairs, vec![Pair("ab", "cd"), Pair("ef", "gh")]);
+
+        let text = buffer.text().as_bytes().as_ptr_range();
+        assert!(text.contains(&pairs[1].0.as_ptr()));
+    }
+
+    #[test]
+    fn packs_split_on_blank_lines() {
+        let buffer = InputBuffer::new("a-b\n\nc-d\ne-f\n");
+        assert_eq!(buffer.packs(), vec![vec!["a-b"], vec!["c-d", "e-f"]]);
+
+        let packs = buffer.parse_packs::<Pair>().unwrap();
+        assert_eq!(packs, vec![vec![Pair("a", "b")], vec![Pair("c", "d"), Pair("e", "f")]]);
+    }
+
+    #[test]
+    fn packs_match_pack() {
+        let cases = ["a\nb\n\nc\n", "a\r\n\r\nb\r\n", "a\n\n\nb", "a\n\n", "", "\n"];
+
+        for input in cases {
+            let collected = crate::pack(input.lines().map(String::from).collect());
+            assert_eq!(InputBuffer::new(input).packs(), collected, "input {:?}", input);
+        }
+    }
+
+    #[test]
+    fn errors_point_into_the_whole_buffer() {
+        let buffer = InputBuffer::new("a-b\n\nc-d\ne+f\n");
+        let error = buffer.parse_packs::<Pair>().unwrap_err();
+        assert_eq!((error.pack, error.line, error.column), (Some(1), 4, Some(2)));
+    }
+}
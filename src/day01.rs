@@ -0,0 +1,119 @@
+use std::io::BufRead;
+
+use crate::topk::top_k;
+use crate::*;
+
+use anyhow::{Context, Result};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: usize = 1;
+    const PART: usize = 1;
+
+    type Input = Vec<Vec<i32>>;
+    type Output = i32;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> Result<Self::Output> {
+        part1(input)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: usize = 1;
+    const PART: usize = 2;
+
+    type Input = Vec<Vec<i32>>;
+    type Output = i32;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> Result<Self::Output> {
+        part2(input)
+    }
+}
+
+pub fn parse(lines: Vec<String>) -> Result<Vec<Vec<i32>>> {
+    validate_pack::<i32>(pack(lines)).into_result().context("error parsing numbers")
+}
+
+// Inputs too large to hold as lines can be read straight from a file.
+pub fn read_packs<R: BufRead>(reader: R) -> Result<Vec<Vec<i32>>> {
+    let validated = validate_pack_reader::<i32, _>(reader).context("error reading packs")?;
+    validated.into_result().context("error parsing numbers")
+}
+
+pub fn part1(packs: &[Vec<i32>]) -> Result<i32> {
+    let top = top_k(calories(packs), 1);
+    let max = top.first().context("no elves to count calories for")?;
+    Ok(max.value)
+}
+
+pub fn part2(packs: &[Vec<i32>]) -> Result<i32> {
+    Ok(top_k(calories(packs), 3).iter().map(|r| r.value).sum())
+}
+
+fn calories(packs: &[Vec<i32>]) -> impl Iterator<Item = i32> + '_ {
+    packs.iter().map(|pack| pack.iter().sum::<i32>())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    crate::examples! {
+        part1: Part1 {
+            example: EXAMPLE => 24000,
+        }
+        part2: Part2 {
+            example: EXAMPLE => 45000,
+        }
+    }
+
+    fn example_input() -> Vec<Vec<i32>> {
+        vec![
+            vec![1000, 2000, 3000],
+            vec![4000],
+            vec![5000, 6000],
+            vec![7000, 8000, 9000],
+            vec![10000],
+        ]
+    }
+
+    #[test]
+    fn packs_are_read_from_a_reader() {
+        let packs = read_packs(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(packs, example_input());
+
+        let error = read_packs("1\n\n2\nx".as_bytes()).unwrap_err();
+        assert!(format!("{:#}", error).contains("pack 2, line 4"));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = example_input();
+        assert_eq!(part1(&input).unwrap(), 24000);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example_input();
+        assert_eq!(part2(&input).unwrap(), 45000);
+    }
+
+    #[test]
+    fn no_packs_have_no_maximum() {
+        assert!(part1(&[]).is_err());
+        assert_eq!(part2(&[]).unwrap(), 0);
+    }
+}
@@ -0,0 +1,347 @@
+use anyhow::{anyhow, Context, Result};
+use nom::IResult;
+use nom::character::complete::{char, one_of};
+use nom::combinator::{map, map_res};
+use nom::sequence::separated_pair;
+
+use crate::*;
+use Response::{X, Y, Z};
+use RoundResult::{Draw, Lost, Won};
+use Shape::{Paper, Rock, Scissors};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: usize = 2;
+    const PART: usize = 1;
+
+    type Input = Game;
+    type Output = u32;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> Result<Self::Output> {
+        part1(input)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: usize = 2;
+    const PART: usize = 2;
+
+    type Input = Game;
+    type Output = u32;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> Result<Self::Output> {
+        part2(input)
+    }
+}
+
+pub fn parse(lines: Vec<String>) -> Result<Game> {
+    Game::try_from(lines).context("error parsing game")
+}
+
+pub fn part1(game: &Game) -> Result<u32> {
+    Ok(game.score(Round::score_by_shape))
+}
+
+pub fn part2(game: &Game) -> Result<u32> {
+    Ok(game.score(Round::score_by_result))
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, FromStrNom)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    fn score(&self) -> u32 {
+        match self {
+            Rock => 1,
+            Paper => 2,
+            Scissors => 3,
+        }
+    }
+
+    fn parse(s: &str) -> IResult<&str, Shape> {
+        let mut parser = map_res(one_of("ABCXYZ"), |c: char| match c {
+            'A' => Ok(Rock),
+            'B' => Ok(Paper),
+            'C' => Ok(Scissors),
+            _ => Err(anyhow!("error")),
+        });
+
+        parser(s)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum RoundResult {
+    Lost,
+    Draw,
+    Won,
+}
+
+impl RoundResult {
+    fn score(&self) -> u32 {
+        match self {
+            Lost => 0,
+            Draw => 3,
+            Won => 6,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, FromStrNom)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl Response {
+    fn shape(&self) -> Shape {
+        match self {
+            X => Rock,
+            Y => Paper,
+            Z => Scissors,
+        }
+    }
+
+    fn result(&self) -> RoundResult {
+        match self {
+            X => Lost,
+            Y => Draw,
+            Z => Won,
+        }
+    }
+
+    fn parse(s: &str) -> IResult<&str, Response> {
+        let mut parser = map_res(one_of("ABCXYZ"), |c: char| match c {
+            'X' => Ok(X),
+            'Y' => Ok(Y),
+            'Z' => Ok(Z),
+            _ => Err(anyhow!("error")),
+        });
+
+        parser(s)
+    }
+}
+
+fn play(me: Shape, opponent: Shape) -> RoundResult {
+    match (me, opponent) {
+        (Rock, Scissors) => Won,
+        (Rock, Rock) => Draw,
+        (Rock, Paper) => Lost,
+
+        (Paper, Rock) => Won,
+        (Paper, Paper) => Draw,
+        (Paper, Scissors) => Lost,
+
+        (Scissors, Paper) => Won,
+        (Scissors, Scissors) => Draw,
+        (Scissors, Rock) => Lost,
+    }
+}
+
+fn derive_my_move(opponent: Shape, result: RoundResult) -> Shape {
+    match (opponent, result) {
+        (Rock, Lost) => Scissors,
+        (Rock, Draw) => Rock,
+        (Rock, Won) => Paper,
+
+        (Paper, Lost) => Rock,
+        (Paper, Draw) => Paper,
+        (Paper, Won) => Scissors,
+
+        (Scissors, Lost) => Paper,
+        (Scissors, Draw) => Scissors,
+        (Scissors, Won) => Rock,
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, FromStrNom)]
+pub struct Round {
+    opponent: Shape,
+    response: Response,
+}
+
+impl Round {
+    fn new(opponent: Shape, response: Response) -> Self {
+        Round { opponent, response }
+    }
+
+    // The second column is read as my shape.
+    fn score_by_shape(&self) -> u32 {
+        let me = self.response.shape();
+        me.score() + play(me, self.opponent).score()
+    }
+
+    // The second column is read as the result the round has to end with.
+    fn score_by_result(&self) -> u32 {
+        let result = self.response.result();
+        derive_my_move(self.opponent, result).score() + result.score()
+    }
+
+    fn parse(s: &str) -> IResult<&str, Round> {
+        let parser = separated_pair(Shape::parse, char(' '), Response::parse);
+        let mut parser = map(parser, |(o, r)| Round::new(o, r));
+        parser(s)
+    }
+}
+
+pub struct Game {
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    fn score(&self, score: impl Fn(&Round) -> u32) -> u32 {
+        self.rounds.iter().map(score).sum()
+    }
+}
+
+impl TryFrom<Vec<String>> for Game {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        let rounds = convert::<Round>(value)?;
+
+        Ok(Game { rounds })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use Response::{X, Y, Z};
+    use RoundResult::{Draw, Lost, Won};
+    use Shape::{Paper, Rock, Scissors};
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z";
+
+    crate::examples! {
+        part1: Part1 {
+            example: EXAMPLE => 15,
+        }
+        part2: Part2 {
+            example: EXAMPLE => 12,
+        }
+    }
+
+    #[test]
+    fn read_shape_from_string() {
+        assert_eq!(Shape::from_str("A").unwrap(), Rock);
+        assert_eq!(Shape::from_str("B").unwrap(), Paper);
+        assert_eq!(Shape::from_str("C").unwrap(), Scissors);
+        assert!(Shape::from_str("").is_err());
+        assert!(Shape::from_str("a").is_err());
+        assert!(Shape::from_str("X").is_err());
+        assert!(Shape::from_str("G").is_err());
+        assert!(Shape::from_str("AX").is_err());
+    }
+
+    #[test]
+    fn read_response_from_string() {
+        assert_eq!(Response::from_str("X").unwrap(), X);
+        assert_eq!(Response::from_str("Y").unwrap(), Y);
+        assert_eq!(Response::from_str("Z").unwrap(), Z);
+        assert!(Response::from_str("").is_err());
+        assert!(Response::from_str("x").is_err());
+        assert!(Response::from_str("A").is_err());
+        assert!(Response::from_str("XA").is_err());
+    }
+
+    #[test]
+    fn responses_are_read_as_shapes_or_results() {
+        assert_eq!((X.shape(), Y.shape(), Z.shape()), (Rock, Paper, Scissors));
+        assert_eq!((X.result(), Y.result(), Z.result()), (Lost, Draw, Won));
+    }
+
+    #[test]
+    fn decide_if_i_have_won() {
+        assert_eq!(play(Rock, Rock), Draw);
+        assert_eq!(play(Paper, Paper), Draw);
+        assert_eq!(play(Scissors, Scissors), Draw);
+
+        assert_eq!(play(Rock, Scissors), Won);
+        assert_eq!(play(Rock, Paper), Lost);
+
+        assert_eq!(play(Paper, Rock), Won);
+        assert_eq!(play(Paper, Scissors), Lost);
+
+        assert_eq!(play(Scissors, Paper), Won);
+        assert_eq!(play(Scissors, Rock), Lost);
+    }
+
+    #[test]
+    fn check_derivation_of_move() {
+        assert_eq!(derive_my_move(Rock, Lost), Scissors);
+        assert_eq!(derive_my_move(Rock, Draw), Rock);
+        assert_eq!(derive_my_move(Rock, Won), Paper);
+
+        assert_eq!(derive_my_move(Paper, Lost), Rock);
+        assert_eq!(derive_my_move(Paper, Draw), Paper);
+        assert_eq!(derive_my_move(Paper, Won), Scissors);
+
+        assert_eq!(derive_my_move(Scissors, Lost), Paper);
+        assert_eq!(derive_my_move(Scissors, Draw), Scissors);
+        assert_eq!(derive_my_move(Scissors, Won), Rock);
+    }
+
+    #[test]
+    fn score_on_round_result() {
+        assert_eq!(Lost.score(), 0);
+        assert_eq!(Draw.score(), 3);
+        assert_eq!(Won.score(), 6);
+    }
+
+    #[test]
+    fn i_can_score_a_game() {
+        let rounds = vec![
+            Round::new(Rock, Y),
+            Round::new(Paper, X),
+            Round::new(Scissors, Z),
+        ];
+        let game = Game { rounds };
+        assert_eq!(part1(&game).unwrap(), 15);
+        assert_eq!(part2(&game).unwrap(), 12);
+    }
+
+    fn shapes() -> impl Strategy<Value = Shape> {
+        prop_oneof![Just(Rock), Just(Paper), Just(Scissors)]
+    }
+
+    fn responses() -> impl Strategy<Value = Response> {
+        prop_oneof![Just(X), Just(Y), Just(Z)]
+    }
+
+    proptest! {
+        #[test]
+        fn round_scores_stay_in_range(opponent in shapes(), response in responses()) {
+            let round = Round::new(opponent, response);
+            prop_assert!((1..=9).contains(&round.score_by_shape()));
+            prop_assert!((1..=9).contains(&round.score_by_result()));
+        }
+
+        #[test]
+        fn derived_moves_reach_the_result(opponent in shapes(), response in responses()) {
+            let result = response.result();
+            prop_assert_eq!(play(derive_my_move(opponent, result), opponent), result);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt::{self, Display, Formatter};
+
+use anyhow::{anyhow, Context, Result};
+use nom::character::complete::alpha1;
+use nom::combinator::map_res;
+use nom::IResult;
+
+use crate::items::{self, ItemSet};
+use crate::*;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: usize = 3;
+    const PART: usize = 1;
+
+    type Input = Vec<Rucksack>;
+    type Output = u32;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> Result<Self::Output> {
+        part1(input)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: usize = 3;
+    const PART: usize = 2;
+
+    type Input = Vec<Rucksack>;
+    type Output = u32;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> Result<Self::Output> {
+        part2(input)
+    }
+}
+
+pub fn parse(lines: Vec<String>) -> Result<Vec<Rucksack>> {
+    convert::<Rucksack>(lines).context("error parsing rucksacks")
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> Result<u32> {
+    process_multiple_rucksacks(rucksacks).context("error processing rucksacks")
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Result<u32> {
+    let result = rucksacks
+        .chunks_exact(3)
+        .map(|x| x.try_into())
+        .collect::<Result<Vec<&[Rucksack; 3]>, _>>()?
+        .iter()
+        .map(|x| process_group(x))
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .sum();
+
+    Ok(result)
+}
+
+#[derive(Debug, Eq, PartialEq, FromStrNom)]
+pub struct Rucksack {
+    first: String,
+    second: String,
+}
+
+impl Rucksack {
+    fn new(first: &str, second: &str) -> Self {
+        Rucksack {
+            first: first.to_string(),
+            second: second.to_string(),
+        }
+    }
+
+    fn compartments(&self) -> [ItemSet; 2] {
+        [ItemSet::from(self.first.as_str()), ItemSet::from(self.second.as_str())]
+    }
+
+    fn items(&self) -> ItemSet {
+        let [first, second] = self.compartments();
+        first | second
+    }
+
+    fn parse(s: &str) -> IResult<&str, Rucksack> {
+        let mut parser = map_res(alpha1, |l: &str| {
+            if !l.len().is_multiple_of(2) {
+                return Err(anyhow!("uneven characters not allowed"));
+            }
+
+            let (first, second) = l.split_at(l.len() / 2);
+            Ok(Rucksack::new(first, second))
+        });
+
+        parser(s)
+    }
+}
+
+fn find_error(rucksack: &Rucksack) -> Option<char> {
+    ItemSet::common(rucksack.compartments()).iter().next()
+}
+
+fn get_priority(c: char) -> Result<u32> {
+    items::priority(c).ok_or_else(|| anyhow!("invalid rucksack item"))
+}
+
+fn process_multiple_rucksacks(rucksacks: &[Rucksack]) -> Result<u32> {
+    let priority = rucksacks
+        .iter()
+        .map(|r| find_error(r).context("no duplicate item").and_then(get_priority))
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .sum();
+
+    Ok(priority)
+}
+
+fn identify_badge(rucksacks: &[Rucksack; 3]) -> Option<char> {
+    ItemSet::common(rucksacks.iter().map(Rucksack::items)).iter().next()
+}
+
+fn process_group(group: &[Rucksack; 3]) -> Result<u32> {
+    let badge = identify_badge(group).context("no badge")?;
+    let priority = get_priority(badge).context("invalid badge")?;
+    Ok(priority)
+}
+
+impl Display for Rucksack {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.first, self.second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::testing::parse_lines;
+
+    use super::*;
+
+    const LINES: [&str; 6] = [
+        "vJrwpWtwJgWrhcsFMMfFFhFp",
+        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        "PmmdzqPrVvPwwTWBwg",
+        "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+        "ttgJtRGJQctTZtZT",
+        "CrZsJsPPZsGzwwsLwLmpwMDw",
+    ];
+
+    fn groups() -> [[Rucksack; 3]; 2] {
+        let mut rucksacks = parse_lines::<Rucksack>(&LINES).into_iter();
+        let mut group = || [(); 3].map(|_| rucksacks.next().unwrap());
+        [group(), group()]
+    }
+
+    crate::examples! {
+        part1: Part1 {
+            example: &LINES.join("\n") => 157,
+        }
+        part2: Part2 {
+            example: &LINES.join("\n") => 70,
+        }
+    }
+
+    #[test]
+    fn can_read_in_rucksack_description() {
+        let case1 = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let expect1 = Rucksack { first: "vJrwpWtwJgWr".to_string(), second: "hcsFMMfFFhFp".to_string() };
+        assert_eq!(Rucksack::from_str(case1).unwrap(), expect1);
+
+        let case2 = "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL";
+        let expect2 = Rucksack { first: "jqHRNqRjqzjGDLGL".to_string(), second: "rsFMfFZSrLrFZsSL".to_string() };
+        assert_eq!(Rucksack::from_str(case2).unwrap(), expect2);
+
+        assert!(Rucksack::from_str("").is_err());
+        assert!(Rucksack::from_str("a").is_err());
+        assert!(Rucksack::from_str("abc").is_err());
+    }
+
+    #[test]
+    fn check_rucksack_for_duplicate_items() {
+        let expected = ['p', 'L', 'P', 'v', 't', 's'];
+        let rucksacks = parse_lines::<Rucksack>(&LINES);
+
+        for i in 0..expected.len() {
+            let r = rucksacks.get(i).unwrap();
+            let e = *expected.get(i).unwrap();
+            assert_eq!(find_error(r), Some(e));
+        }
+    }
+
+    #[test]
+    fn can_calculate_the_priority() {
+        assert_eq!(get_priority('p').unwrap(), 16);
+        assert_eq!(get_priority('L').unwrap(), 38);
+        assert_eq!(get_priority('P').unwrap(), 42);
+        assert_eq!(get_priority('v').unwrap(), 22);
+        assert_eq!(get_priority('t').unwrap(), 20);
+        assert_eq!(get_priority('s').unwrap(), 19);
+        assert!(get_priority('0').is_err());
+    }
+
+    #[test]
+    fn check_all_rucksacks() {
+        let rucksacks = parse_lines::<Rucksack>(&LINES);
+
+        let priority = process_multiple_rucksacks(&rucksacks);
+        assert_eq!(priority.unwrap(), 157);
+    }
+
+    #[test]
+    fn rucksacks_without_a_duplicate_are_an_error() {
+        let rucksacks = parse_lines::<Rucksack>(&["abcd"]);
+        assert_eq!(find_error(&rucksacks[0]), None);
+        assert!(process_multiple_rucksacks(&rucksacks).is_err());
+    }
+
+    #[test]
+    fn can_identify_badge() {
+        let [group1, group2] = groups();
+
+        assert_eq!(identify_badge(&group1), Some('r'));
+        assert_eq!(identify_badge(&group2), Some('Z'));
+    }
+
+    #[test]
+    fn can_process_a_group() {
+        let [group1, group2] = groups();
+
+        assert_eq!(process_group(&group1).unwrap(), 18);
+        assert_eq!(process_group(&group2).unwrap(), 52);
+    }
+}
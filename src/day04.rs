@@ -0,0 +1,227 @@
+use std::fmt::{self, Display, Formatter};
+
+use anyhow::Context;
+use nom::character::complete::{char, u64};
+use nom::combinator::{map, map_opt};
+use nom::sequence::separated_pair;
+
+use crate::interval::Interval;
+use crate::{convert, FromStrNom, Solution};
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: usize = 4;
+    const PART: usize = 1;
+
+    type Input = Vec<SectionPair>;
+    type Output = usize;
+
+    fn parse(lines: Vec<String>) -> anyhow::Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> anyhow::Result<Self::Output> {
+        part1(input)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: usize = 4;
+    const PART: usize = 2;
+
+    type Input = Vec<SectionPair>;
+    type Output = usize;
+
+    fn parse(lines: Vec<String>) -> anyhow::Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> anyhow::Result<Self::Output> {
+        part2(input)
+    }
+}
+
+pub fn parse(lines: Vec<String>) -> anyhow::Result<Vec<SectionPair>> {
+    convert::<SectionPair>(lines).context("error parsing section pairs")
+}
+
+pub fn part1(pairs: &[SectionPair]) -> anyhow::Result<usize> {
+    Ok(count_pairs(pairs, SectionPair::fully_overlap))
+}
+
+pub fn part2(pairs: &[SectionPair]) -> anyhow::Result<usize> {
+    Ok(count_pairs(pairs, SectionPair::overlap))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, FromStrNom)]
+pub struct AssignmentSection(Interval<u64>);
+
+impl AssignmentSection {
+    fn new(start: u64, end: u64) -> Option<Self> {
+        Interval::new(start, end).map(AssignmentSection)
+    }
+
+    fn fully_contains(&self, other: &Self) -> bool {
+        self.0.contains_interval(&other.0)
+    }
+
+    fn overlap(&self, other: &Self) -> bool {
+        self.0.overlaps(&other.0)
+    }
+
+    fn parse(s: &str) -> nom::IResult<&str, AssignmentSection> {
+        let parser = separated_pair(u64, char('-'), u64);
+        let mut parser = map_opt(parser, |(x, y): (u64, u64)| AssignmentSection::new(x, y));
+        parser(s)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, FromStrNom)]
+pub struct SectionPair(AssignmentSection, AssignmentSection);
+
+impl SectionPair {
+    fn fully_overlap(&self) -> bool {
+        let x = self.0.fully_contains(&self.1);
+        let y = self.1.fully_contains(&self.0);
+        x || y
+    }
+
+    fn overlap(&self) -> bool {
+        let x = self.0.overlap(&self.1);
+        let y = self.1.overlap(&self.0);
+        x || y
+    }
+
+    fn parse(s: &str) -> nom::IResult<&str, SectionPair> {
+        let parser = separated_pair(AssignmentSection::parse, char(','), AssignmentSection::parse);
+        let mut parser = map(parser, |(x, y): (AssignmentSection, AssignmentSection)| SectionPair(x, y));
+        parser(s)
+    }
+}
+
+impl Display for AssignmentSection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Display for SectionPair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+fn count_pairs(pairs: &[SectionPair], predicate: impl Fn(&SectionPair) -> bool) -> usize {
+    pairs
+        .iter()
+        .filter(|p| predicate(p))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use proptest::prelude::*;
+
+    use crate::testing::parse_lines;
+
+    use super::*;
+
+    const LINES: [&str; 6] = [
+        "2-4,6-8",
+        "2-3,4-5",
+        "5-7,7-9",
+        "2-8,3-7",
+        "6-6,4-6",
+        "2-6,4-8",
+    ];
+
+    fn range(start: u64, end: u64) -> AssignmentSection {
+        AssignmentSection::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn fully_contains_works() {
+        let section = range(5, 10);
+
+        let inner = range(6, 8);
+        let left_overlap = range(1, 8);
+        let right_overlap = range(6, 11);
+        let outer = range(0, 15);
+
+        assert!(section.fully_contains(&inner));
+        assert!(!inner.fully_contains(&section));
+
+        assert!(!section.fully_contains(&left_overlap));
+        assert!(!section.fully_contains(&right_overlap));
+        assert!(!section.fully_contains(&outer));
+    }
+
+    #[test]
+    fn parse_section_pair_works() {
+        let pair = SectionPair::from_str("2-4,6-8").unwrap();
+        assert_eq!(pair, SectionPair(range(2, 4), range(6, 8)));
+        assert!(SectionPair::from_str("4-2,6-8").is_err());
+    }
+
+    #[test]
+    fn pair_fully_overlap_works() {
+        let pair = SectionPair(
+            range(5, 10),
+            range(6, 8),
+        );
+        assert!(pair.fully_overlap());
+
+        let pair_switched = SectionPair(
+            range(6, 8),
+            range(5, 10),
+        );
+        assert!(pair_switched.fully_overlap())
+    }
+
+    #[test]
+    fn overlap_works() {
+        let pairs = parse_lines::<SectionPair>(&LINES);
+        let overlapping = pairs.iter().map(SectionPair::overlap).collect::<Vec<_>>();
+        assert_eq!(overlapping, vec![false, false, true, true, true, true]);
+    }
+
+    #[test]
+    fn count_enclosed_pairs_works() {
+        let pairs = parse_lines::<SectionPair>(&LINES);
+        assert_eq!(part1(&pairs).unwrap(), 2);
+    }
+
+    #[test]
+    fn count_overlapping_pairs_works() {
+        let pairs = parse_lines::<SectionPair>(&LINES);
+        assert_eq!(part2(&pairs).unwrap(), 4);
+    }
+
+    fn sections() -> impl Strategy<Value = AssignmentSection> {
+        (0..100u64, 0..100u64).prop_map(|(a, b)| range(a.min(b), a.max(b)))
+    }
+
+    proptest! {
+        #[test]
+        fn fully_contains_implies_overlap(a in sections(), b in sections()) {
+            if a.fully_contains(&b) {
+                prop_assert!(a.overlap(&b));
+            }
+        }
+
+        #[test]
+        fn overlap_is_symmetric(a in sections(), b in sections()) {
+            prop_assert_eq!(a.overlap(&b), b.overlap(&a));
+        }
+
+        #[test]
+        fn sections_contain_themselves(a in sections()) {
+            prop_assert!(a.fully_contains(&a));
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use nom::bytes::complete::tag;
+use nom::character::complete::u64;
+use nom::combinator::map;
+use nom::sequence::tuple;
+
+use crate::diagram::Layout;
+use crate::*;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: usize = 5;
+    const PART: usize = 1;
+
+    type Input = (Stacks, Vec<Move>);
+    type Output = String;
+
+    fn parse(lines: Vec<String>) -> anyhow::Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> anyhow::Result<Self::Output> {
+        part1(input)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: usize = 5;
+    const PART: usize = 2;
+
+    type Input = (Stacks, Vec<Move>);
+    type Output = String;
+
+    fn parse(lines: Vec<String>) -> anyhow::Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> anyhow::Result<Self::Output> {
+        part2(input)
+    }
+}
+
+pub fn parse(lines: Vec<String>) -> anyhow::Result<(Stacks, Vec<Move>)> {
+    let mut packs = pack(lines).into_iter();
+    let stacks = packs.next().context("error getting stacks")?;
+    let moves = packs.next().context("error getting moves")?;
+    let offset = stacks.len() + 1;
+
+    let stacks = Stacks::from_str(&stacks.join("\n"))
+        .context("error parsing stacks")?;
+
+    let moves = convert::<Move>(moves)
+        .map_err(|e| e.offset_lines(offset))
+        .context("error parsing moves")?;
+
+    Ok((stacks, moves))
+}
+
+pub fn part1((stacks, moves): &(Stacks, Vec<Move>)) -> anyhow::Result<String> {
+    let mut stacks = stacks.clone();
+    stacks.execute_many(moves, Crane::CrateMover9000)?;
+    Ok(stacks.get_tops())
+}
+
+pub fn part2((stacks, moves): &(Stacks, Vec<Move>)) -> anyhow::Result<String> {
+    let mut stacks = stacks.clone();
+    stacks.execute_many(moves, Crane::CrateMover9001)?;
+    Ok(stacks.get_tops())
+}
+
+// The 9000 moves crates one at a time, the 9001 picks up all of them at once and keeps their order.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Crane {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, FromStrNom)]
+pub struct Move {
+    many: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.many, self.from, self.to)
+    }
+}
+
+impl Move {
+    fn new(many: usize, from: usize, to: usize) -> Self {
+        Move { many, from, to }
+    }
+
+    fn parse(s: &str) -> nom::IResult<&str, Self> {
+        let parser = (tag("move "), u64, tag(" from "), u64, tag(" to "), u64);
+        let mut parser = map(tuple(parser), |(_, m, _, f, _, t)| Move::new(m as usize, f as usize, t as usize));
+        parser(s)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Stacks {
+    data: Vec<Vec<String>>,
+    fork: Vec<String>,
+}
+
+impl FromStr for Stacks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = Layout::crates().parse(s)?.stacks();
+        Ok(Stacks { data, ..Stacks::new(0) })
+    }
+}
+
+impl Stacks {
+    fn new(n: usize) -> Self {
+        Stacks { data: vec![Vec::new(); n], fork: Vec::new() }
+    }
+
+    // Moves that refer to missing stacks or take more crates than there are are rejected.
+    fn execute(&mut self, mov: Move, crane: Crane) -> anyhow::Result<()> {
+        let stacks = 1..=self.data.len();
+        if !stacks.contains(&mov.from) || !stacks.contains(&mov.to) {
+            bail!("there are only {} stacks", self.data.len());
+        }
+
+        let height = self.data[mov.from - 1].len();
+        if mov.many > height {
+            bail!("stack {} only holds {} crates", mov.from, height);
+        }
+
+        self.fork.extend(self.data[mov.from - 1].drain(height - mov.many..).rev());
+
+        if crane == Crane::CrateMover9000 {
+            self.fork.reverse();
+        }
+
+        while let Some(v) = self.fork.pop() {
+            self.data[mov.to - 1].push(v);
+        }
+
+        Ok(())
+    }
+
+    fn execute_many(&mut self, moves: &[Move], crane: Crane) -> anyhow::Result<()> {
+        for (i, &m) in moves.iter().enumerate() {
+            self.execute(m, crane).with_context(|| format!("error executing move {} ({})", i + 1, m))?;
+        }
+        Ok(())
+    }
+
+    fn get_tops(&self) -> String {
+        let mut tops = String::new();
+
+        for i in self.data.iter() {
+            if let Some(v) = i.last() {
+                tops += v;
+            }
+        }
+
+        tops
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const STACKS: &str = "[A]\n[B] [C]\n 1   2\n\n";
+
+    crate::examples! {
+        part1: Part1 {
+            stack_zero: &format!("{}move 1 from 0 to 1", STACKS) => error("there are only 2 stacks"),
+            missing_stack: &format!("{}move 1 from 1 to 3", STACKS) => error("move 1 (move 1 from 1 to 3)"),
+            too_many_crates: &format!("{}move 3 from 1 to 2", STACKS) => error("stack 1 only holds 2 crates"),
+        }
+        part2: Part2 {
+            stack_zero: &format!("{}move 1 from 1 to 0", STACKS) => error("there are only 2 stacks"),
+            missing_stack: &format!("{}move 1 from 3 to 1", STACKS) => error("there are only 2 stacks"),
+            too_many_crates: &format!("{}move 1 from 2 to 1\nmove 4 from 1 to 2", STACKS) => error("move 2 (move 4 from 1 to 2): stack 1 only holds 3"),
+        }
+    }
+
+    #[test]
+    fn saved_example_works() {
+        crate::testing::check_saved_example::<Part1>();
+        crate::testing::check_saved_example::<Part2>();
+    }
+
+    #[test]
+    fn getting_top_state_works() -> anyhow::Result<()> {
+        let mut stacks = Stacks::new(3);
+
+        stacks.data[0] = vec![];
+        stacks.data[1] = vec!["A".to_string(), "B".to_string()];
+        stacks.data[2] = vec!["Z".to_string()];
+
+        assert_eq!(stacks.get_tops(), "BZ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_execution_works() -> anyhow::Result<()> {
+        let mut stacks = Stacks::new(2);
+        stacks.data[0] = vec!["A".to_string(), "B".to_string()];
+        let mut stacks_9001 = stacks.clone();
+
+        stacks.execute(Move::new(2, 1, 2), Crane::CrateMover9000).unwrap();
+
+        assert_eq!(stacks.data[0], Vec::new() as Vec<String>);
+        assert_eq!(stacks.data[1], vec!["B", "A"]);
+
+        stacks_9001.execute(Move::new(2, 1, 2), Crane::CrateMover9001).unwrap();
+
+        assert_eq!(stacks_9001.data[0], Vec::new() as Vec<String>);
+        assert_eq!(stacks_9001.data[1], vec!["A", "B"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_execution_of_many_works() -> anyhow::Result<()> {
+        let mut stacks = Stacks::new(2);
+        stacks.data[0] = vec!["A".to_string(), "B".to_string()];
+        let mut stacks_9001 = stacks.clone();
+
+        let moves = vec![
+            Move::new(2, 1, 2),
+            Move::new(1, 2, 1),
+        ];
+
+        stacks.execute_many(&moves, Crane::CrateMover9000).unwrap();
+
+        assert_eq!(stacks.data[0], vec!["A"]);
+        assert_eq!(stacks.data[1], vec!["B"]);
+
+        stacks_9001.execute_many(&moves, Crane::CrateMover9001).unwrap();
+
+        assert_eq!(stacks_9001.data[0], vec!["B"]);
+        assert_eq!(stacks_9001.data[1], vec!["A"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_input() -> anyhow::Result<()> {
+        let packs = pack(crate::testing::saved_example(5));
+        let stacks = packs.first().context("error getting boards")?;
+        let moves = packs.get(1).context("error getting moves")?;
+
+        let stacks = Stacks::from_str(&stacks.join("\n"))
+            .context("error parsing stacks")?;
+
+        let moves = moves
+            .iter()
+            .map(|l| Move::from_str(l))
+            .collect::<Result<Vec<_>, _>>()
+            .context("error parsing moves")?;
+
+        // check stacks
+        assert_eq!(stacks.data.len(), 3);
+        assert_eq!(stacks.data[0], vec!["Z", "N"]);
+        assert_eq!(stacks.data[1], vec!["M", "C", "D"]);
+        assert_eq!(stacks.data[2], vec!["P"]);
+
+        // check moves
+        assert_eq!(moves.len(), 4);
+        assert_eq!(moves[0], Move::new(1, 2, 1));
+        assert_eq!(moves[1], Move::new(3, 1, 3));
+        assert_eq!(moves[2], Move::new(2, 2, 1));
+        assert_eq!(moves[3], Move::new(1, 1, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rows_may_be_longer_than_the_top_row() {
+        let stacks = Stacks::from_str("[A]\n[B] [C]\n 1   2").unwrap();
+        assert_eq!(stacks.data, vec![vec!["B", "A"], vec!["C"]]);
+    }
+
+    fn crate_count(stacks: &Stacks) -> usize {
+        stacks.data.iter().map(|s| s.len()).sum()
+    }
+
+    // Stacks of single letter crates together with a move that only takes crates that are there.
+    fn stacks_and_move() -> impl Strategy<Value = (Stacks, Move)> {
+        let stacks = prop::collection::vec(prop::collection::vec("[A-Z]", 0..8), 1..6);
+        stacks.prop_flat_map(|data| {
+            let n = data.len();
+            (Just(data), 1..=n, 1..=n).prop_flat_map(|(data, from, to)| {
+                let available = data[from - 1].len();
+                (Just(data), 0..=available, Just(from), Just(to))
+            })
+        })
+        .prop_map(|(data, many, from, to)| {
+            let mut stacks = Stacks::new(data.len());
+            stacks.data = data;
+            (stacks, Move::new(many, from, to))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn moves_preserve_the_crate_count((stacks, mov) in stacks_and_move()) {
+            for crane in [Crane::CrateMover9000, Crane::CrateMover9001] {
+                let mut moved = stacks.clone();
+                moved.execute(mov, crane).unwrap();
+                prop_assert_eq!(crate_count(&moved), crate_count(&stacks));
+            }
+        }
+
+        #[test]
+        fn cranes_differ_only_in_order((stacks, mov) in stacks_and_move()) {
+            let mut single = stacks.clone();
+            let mut multiple = stacks.clone();
+            single.execute(mov, Crane::CrateMover9000).unwrap();
+            multiple.execute(mov, Crane::CrateMover9001).unwrap();
+
+            let top = |s: &Stacks| {
+                let mut top = s.data[mov.to - 1].iter().rev().take(mov.many).cloned().collect::<Vec<_>>();
+                top.sort();
+                top
+            };
+            prop_assert_eq!(top(&single), top(&multiple));
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::{anyhow, Context};
+
+use crate::window::distinct_byte_windows;
+use crate::Solution;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: usize = 6;
+    const PART: usize = 1;
+
+    type Input = String;
+    type Output = usize;
+
+    fn parse(lines: Vec<String>) -> anyhow::Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> anyhow::Result<Self::Output> {
+        part1(input)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: usize = 6;
+    const PART: usize = 2;
+
+    type Input = String;
+    type Output = usize;
+
+    fn parse(lines: Vec<String>) -> anyhow::Result<Self::Input> {
+        parse(lines)
+    }
+
+    fn solve(input: &Self::Input) -> anyhow::Result<Self::Output> {
+        part2(input)
+    }
+}
+
+const PACKET_MARKER_LENGTH: usize = 4;
+const MESSAGE_MARKER_LENGTH: usize = 14;
+
+pub fn parse(lines: Vec<String>) -> anyhow::Result<String> {
+    lines.into_iter().next().context("error reading buffer")
+}
+
+pub fn part1(buffer: &str) -> anyhow::Result<usize> {
+    find_marker(buffer, PACKET_MARKER_LENGTH).context("error finding marker")
+}
+
+pub fn part2(buffer: &str) -> anyhow::Result<usize> {
+    find_marker(buffer, MESSAGE_MARKER_LENGTH).context("error finding marker")
+}
+
+fn find_marker(buffer: &str, length: usize) -> anyhow::Result<usize> {
+    if buffer.len() < length {
+        return Err(anyhow!("not enough characters in buffer"));
+    }
+
+    distinct_byte_windows(buffer.as_bytes(), length)
+        .next()
+        .context("was not able to find marker in stream")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    crate::examples! {
+        part1: Part1 {
+            too_short: "abc" => error("not enough characters"),
+            no_marker: "aaaaaaaaaaaaa" => error("was not able to find marker"),
+            example1: "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => 7,
+            example2: "bvwbjplbgvbhsrlpgdmjqwftvncz" => 5,
+            example3: "nppdvjthqldpwncqszvftbrmjlhg" => 6,
+            example4: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg" => 10,
+            example5: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => 11,
+        }
+        part2: Part2 {
+            too_short: "abcdefghijklm" => error("not enough characters"),
+            example1: "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => 19,
+            example2: "bvwbjplbgvbhsrlpgdmjqwftvncz" => 23,
+            example3: "nppdvjthqldpwncqszvftbrmjlhg" => 23,
+            example4: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg" => 29,
+            example5: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => 26,
+        }
+    }
+
+    fn distinct(window: &[u8]) -> bool {
+        window.iter().collect::<HashSet<_>>().len() == window.len()
+    }
+
+    proptest! {
+        #[test]
+        fn markers_are_the_first_distinct_window(buffer in "[a-f]{0,40}", length in 1..6usize) {
+            let bytes = buffer.as_bytes();
+
+            match find_marker(&buffer, length) {
+                Ok(end) => {
+                    prop_assert!(distinct(&bytes[end - length..end]));
+                    prop_assert!(bytes[..end - 1].windows(length).all(|w| !distinct(w)));
+                }
+                Err(_) => prop_assert!(bytes.windows(length).all(|w| !distinct(w))),
+            }
+        }
+    }
+}
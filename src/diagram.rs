@@ -0,0 +1,291 @@
+use crate::ParseError;
+
+// Column-aligned ASCII drawings such as the crate stacks of day 05:
+//
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+//
+// Every cell is `cell_width` characters wide and separated from the next by `gap` spaces. Blank
+// cells are empty, the others may be wrapped in delimiters and an optional last row labels the columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    cell_width: usize,
+    gap: usize,
+    delimiters: Option<(char, char)>,
+    footer: bool,
+    numbered: bool,
+}
+
+impl Layout {
+    // A zero `cell_width` is rejected when parsing.
+    pub fn new(cell_width: usize) -> Self {
+        Layout { cell_width, gap: 1, delimiters: None, footer: false, numbered: false }
+    }
+
+    // `[X]` cells with one space in between and a row of stack numbers below.
+    pub fn crates() -> Self {
+        Layout::new(3).delimiters('[', ']').numbered()
+    }
+
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn delimiters(mut self, open: char, close: char) -> Self {
+        self.delimiters = Some((open, close));
+        self
+    }
+
+    // The last row labels the columns, every label cell has to be filled.
+    pub fn footer(mut self) -> Self {
+        self.footer = true;
+        self
+    }
+
+    // A footer counting the columns up from 1.
+    pub fn numbered(mut self) -> Self {
+        self.numbered = true;
+        self.footer()
+    }
+
+    pub fn parse(&self, text: &str) -> Result<Diagram, ParseError> {
+        if self.cell_width == 0 {
+            return Err(ParseError::new("cell width of at least one"));
+        }
+
+        let mut lines = text.lines().collect::<Vec<_>>();
+        while lines.last().is_some_and(|l| l.trim().is_empty()) {
+            lines.pop();
+        }
+
+        let labels = match self.footer {
+            true => {
+                let footer = lines.pop().ok_or_else(|| ParseError::new("diagram with a label row"))?;
+                Some(self.labels(footer, lines.len() + 1)?)
+            }
+            false => None,
+        };
+
+        let mut rows = Vec::with_capacity(lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            let cells = self.cells(line, i + 1)?;
+            let row = cells
+                .iter()
+                .enumerate()
+                .map(|(n, cell)| self.content(cell).map_err(|e| error(i + 1, self.column(n), line, e)))
+                .collect::<Result<Vec<_>, _>>()?;
+            rows.push(row);
+        }
+
+        let width = rows.iter().map(|r| r.len()).chain(labels.as_ref().map(|l| l.len())).max().unwrap_or(0);
+        if let Some(labels) = &labels {
+            if let Some(i) = rows.iter().position(|r| r.len() > labels.len()) {
+                return Err(error(i + 1, self.column(labels.len()), lines[i], "cell below a column label"));
+            }
+            if rows.is_empty() && labels.is_empty() {
+                return Err(ParseError::new("diagram with at least one column"));
+            }
+        }
+
+        for row in &mut rows {
+            row.resize(width, None);
+        }
+
+        Ok(Diagram { rows, labels })
+    }
+
+    fn labels(&self, footer: &str, line: usize) -> Result<Vec<String>, ParseError> {
+        let cells = self.cells(footer, line)?;
+        let mut labels = Vec::with_capacity(cells.len());
+
+        for (n, cell) in cells.iter().enumerate() {
+            let label = cell.trim();
+            let expected = match self.numbered {
+                true if label != (n + 1).to_string() => Some(format!("column number {}", n + 1)),
+                false if label.is_empty() => Some("column label".to_string()),
+                _ => None,
+            };
+
+            if let Some(expected) = expected {
+                return Err(error(line, self.column(n), footer, &expected));
+            }
+            labels.push(label.to_string());
+        }
+
+        Ok(labels)
+    }
+
+    fn column(&self, cell: usize) -> usize {
+        cell * (self.cell_width + self.gap) + 1
+    }
+
+    // Splits a line into cells, the last one may be cut short by trimmed trailing spaces.
+    fn cells<'a>(&self, line: &'a str, line_number: usize) -> Result<Vec<&'a str>, ParseError> {
+        let chars = line.char_indices().map(|(i, _)| i).chain([line.len()]).collect::<Vec<_>>();
+        let length = chars.len() - 1;
+        let mut cells = Vec::new();
+
+        let mut start = 0;
+        while start < length {
+            let end = (start + self.cell_width).min(length);
+            cells.push(&line[chars[start]..chars[end]]);
+
+            let gap_end = (end + self.gap).min(length);
+            if !line[chars[end]..chars[gap_end]].chars().all(|c| c == ' ') {
+                return Err(error(line_number, end + 1, line, "space between cells"));
+            }
+            start = gap_end;
+        }
+
+        Ok(cells)
+    }
+
+    fn content(&self, cell: &str) -> Result<Option<String>, &'static str> {
+        if cell.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let content = match self.delimiters {
+            Some((open, close)) => cell
+                .strip_prefix(open)
+                .and_then(|c| c.strip_suffix(close))
+                .filter(|_| cell.chars().count() == self.cell_width)
+                .ok_or("delimited cell")?,
+            None => cell,
+        };
+
+        match content.trim() {
+            "" => Err("cell content"),
+            content => Ok(Some(content.to_string())),
+        }
+    }
+}
+
+fn error(line: usize, column: usize, snippet: &str, expected: &str) -> ParseError {
+    ParseError { line, column: Some(column), ..ParseError::new(expected) }.with_snippet(snippet)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagram {
+    rows: Vec<Vec<Option<String>>>,
+    labels: Option<Vec<String>>,
+}
+
+impl Diagram {
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, |r| r.len()).max(self.labels.as_ref().map_or(0, |l| l.len()))
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn labels(&self) -> Option<&[String]> {
+        self.labels.as_deref()
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&str> {
+        self.rows.get(row)?.get(column)?.as_deref()
+    }
+
+    // Row-major, from the top row down.
+    pub fn rows(&self) -> &[Vec<Option<String>>] {
+        &self.rows
+    }
+
+    // Column-major, every column from the top row down.
+    pub fn columns(&self) -> Vec<Vec<Option<String>>> {
+        (0..self.width()).map(|c| self.rows.iter().map(|r| r.get(c).cloned().flatten()).collect()).collect()
+    }
+
+    // The filled cells of every column from the bottom up, i.e. piles with their top last.
+    pub fn stacks(&self) -> Vec<Vec<String>> {
+        self.columns().into_iter().map(|c| c.into_iter().rev().flatten().collect()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CRATES: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ";
+
+    fn owned(cells: &[Option<&str>]) -> Vec<Option<String>> {
+        cells.iter().map(|c| c.map(String::from)).collect()
+    }
+
+    #[test]
+    fn crate_diagrams_become_stacks() {
+        let diagram = Layout::crates().parse(CRATES).unwrap();
+
+        assert_eq!((diagram.width(), diagram.height()), (3, 3));
+        assert_eq!(diagram.labels(), Some(&["1".to_string(), "2".to_string(), "3".to_string()][..]));
+        assert_eq!(diagram.stacks(), vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]]);
+        assert_eq!(diagram.get(0, 1), Some("D"));
+        assert_eq!(diagram.get(0, 0), None);
+    }
+
+    #[test]
+    fn rows_and_columns_keep_empty_cells() {
+        let diagram = Layout::crates().parse(CRATES).unwrap();
+
+        assert_eq!(diagram.rows()[0], owned(&[None, Some("D"), None]));
+        assert_eq!(diagram.columns()[2], owned(&[None, None, Some("P")]));
+    }
+
+    #[test]
+    fn trimmed_lines_and_ragged_rows_are_padded() {
+        let diagram = Layout::crates().parse("    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3").unwrap();
+        assert_eq!(diagram.stacks(), vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]]);
+
+        let diagram = Layout::crates().parse("[A]\n[B] [C]\n 1   2").unwrap();
+        assert_eq!(diagram.stacks(), vec![vec!["B", "A"], vec!["C"]]);
+    }
+
+    #[test]
+    fn layouts_are_configurable() {
+        let diagram = Layout::new(1).gap(0).parse("ab.\n.cd").unwrap();
+        assert_eq!(diagram.rows()[0], owned(&[Some("a"), Some("b"), Some(".")]));
+        assert_eq!(diagram.labels(), None);
+
+        let diagram = Layout::new(4).gap(2).delimiters('<', '>').parse("<ab>        <cd>").unwrap();
+        assert_eq!(diagram.rows()[0], owned(&[Some("ab"), None, Some("cd")]));
+    }
+
+    #[test]
+    fn malformed_diagrams_point_at_the_cell() {
+        let error = Layout::crates().parse("[A] [B\n 1   2").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, Some(5), "delimited cell"));
+
+        let error = Layout::crates().parse("[A]x[B]\n 1   2").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, Some(4), "space between cells"));
+
+        let error = Layout::crates().parse("[A] [B]\n 1").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, Some(5), "cell below a column label"));
+
+        assert!(Layout::crates().parse("").is_err());
+        assert!(Layout::crates().parse("[]\n 1").is_err());
+    }
+
+    #[test]
+    fn labels_are_checked() {
+        let error = Layout::crates().parse("[A] [B]\n 1   3").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, Some(5), "column number 2"));
+        assert!(Layout::crates().parse("[A] [B]\n 1   x").is_err());
+
+        let error = Layout::new(1).footer().parse("a b c\nx   z").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, Some(3), "column label"));
+
+        let diagram = Layout::new(1).footer().parse("a b\nx y").unwrap();
+        assert_eq!(diagram.labels(), Some(&["x".to_string(), "y".to_string()][..]));
+    }
+
+    #[test]
+    fn cells_have_a_width() {
+        let error = Layout::new(0).parse("ab").unwrap_err();
+        assert_eq!(error.expected, "cell width of at least one");
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    num::{ParseFloatError, ParseIntError},
+};
+
+use nom::{Finish, IResult};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub pack: Option<usize>,
+    pub line: usize,
+    pub column: Option<usize>,
+    pub expected: String,
+    pub snippet: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(expected: impl Into<String>) -> Self {
+        ParseError {
+            pack: None,
+            line: 1,
+            column: None,
+            expected: expected.into(),
+            snippet: None,
+        }
+    }
+
+    pub fn from_nom<T>(input: &str, error: nom::error::Error<&str>) -> Self {
+        let offset = input.len() - error.input.len();
+        let consumed = &input[..offset];
+
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        let column = consumed[line_start..].chars().count() + 1;
+        let snippet = input[line_start..].lines().next().unwrap_or_default();
+
+        ParseError {
+            pack: None,
+            line,
+            column: Some(column),
+            expected: format!("{} ({})", type_name::<T>(), error.code.description()),
+            snippet: Some(snippet.to_string()),
+        }
+    }
+
+    // Line numbers start relative to the parsed string; callers shift them into file positions.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    pub fn in_pack(mut self, pack: usize) -> Self {
+        self.pack = Some(pack);
+        self
+    }
+
+    pub fn with_snippet(mut self, snippet: &str) -> Self {
+        if self.snippet.is_none() {
+            self.snippet = Some(snippet.to_string());
+        }
+        self
+    }
+}
+
+pub fn finish<T>(input: &str, result: IResult<&str, T>) -> Result<T, ParseError> {
+    match result.finish() {
+        Ok((_, v)) => Ok(v),
+        Err(e) => Err(ParseError::from_nom::<T>(input, e)),
+    }
+}
+
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(pack) = self.pack {
+            write!(f, "pack {}, ", pack + 1)?;
+        }
+
+        match self.column {
+            Some(column) => write!(f, "line {}, column {}: expected {}", self.line, column, self.expected)?,
+            None => write!(f, "line {}: expected {}", self.line, self.expected)?,
+        }
+
+        if let Some(snippet) = &self.snippet {
+            write!(f, "\n  {}", snippet)?;
+            if let Some(column) = self.column {
+                write!(f, "\n  {:>width$}", "^", width = column)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseErrors(pub Vec<ParseError>);
+
+impl ParseErrors {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ParseError> {
+        self.0.iter()
+    }
+}
+
+impl Display for ParseErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} parse error(s)", self.0.len())?;
+        for e in &self.0 {
+            write!(f, "\n{}", e)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseErrors {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(e: ParseIntError) -> Self {
+        ParseError::new(format!("integer ({})", e))
+    }
+}
+
+impl From<ParseFloatError> for ParseError {
+    fn from(e: ParseFloatError) -> Self {
+        ParseError::new(format!("float ({})", e))
+    }
+}
+
+impl From<Infallible> for ParseError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+
+    use super::*;
+
+    struct Move;
+
+    #[test]
+    fn position_is_derived_from_remaining_input() {
+        let input = "move 1 from 2 to 3\nmove x from 1 to 2";
+        let error = nom::error::Error { input: &input[24..], code: ErrorKind::Digit };
+        let error = ParseError::from_nom::<Move>(input, error);
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, Some(6));
+        assert_eq!(error.expected, "Move (Digit)");
+        assert_eq!(error.snippet.as_deref(), Some("move x from 1 to 2"));
+    }
+
+    #[test]
+    fn display_underlines_the_failing_column() {
+        let error = nom::error::Error { input: "x", code: ErrorKind::Digit };
+        let error = ParseError::from_nom::<Move>("ab x", error).offset_lines(4);
+
+        let expected = "line 5, column 4: expected Move (Digit)\n  ab x\n     ^";
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn display_names_the_pack() {
+        let error = ParseError::new("integer").in_pack(1).offset_lines(3);
+        assert_eq!(error.to_string(), "pack 2, line 4: expected integer");
+    }
+
+    #[test]
+    fn foreign_errors_keep_the_first_snippet() {
+        let error: ParseError = "12a".parse::<i32>().unwrap_err().into();
+        let error = error.with_snippet("12a").with_snippet("other");
+
+        assert_eq!(error.column, None);
+        assert_eq!(error.to_string(), "line 1: expected integer (invalid digit found in string)\n  12a");
+    }
+}
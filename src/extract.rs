@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+
+use crate::input::input_dir;
+
+pub const EXAMPLE_VARIANT: &str = "example";
+
+pub fn examples_path() -> PathBuf {
+    input_dir().join("examples.toml")
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Extracted {
+    pub examples: Vec<String>,
+    pub answers: Vec<Option<String>>,
+}
+
+// Every `<article>` describes one part; its first code block is the example input, later ones show
+// intermediate states. The last emphasized code outside of the blocks is the example's answer.
+pub fn extract(html: &str) -> Extracted {
+    let mut extracted = Extracted::default();
+
+    for article in elements(html, "article") {
+        if let Some(code) = elements(article, "pre").find_map(|pre| elements(pre, "code").next()) {
+            extracted.examples.push(decode(&strip_tags(code)));
+        }
+
+        let mut prose = article.to_string();
+        for pre in elements(article, "pre") {
+            prose = prose.replace(pre, "");
+        }
+
+        let answer = elements(&prose, "code")
+            .filter_map(|code| elements(code, "em").next())
+            .last()
+            .map(|em| decode(&strip_tags(em)));
+        extracted.answers.push(answer);
+    }
+
+    extracted
+}
+
+fn elements<'a>(html: &'a str, tag: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut rest = html;
+
+    std::iter::from_fn(move || loop {
+        let start = rest.find(&open)?;
+        let after = &rest[start + open.len()..];
+
+        // skip tags that merely start with the same name, e.g. `<em>` vs `<embed>`
+        if !after.starts_with(['>', ' ', '\n', '\t']) {
+            rest = after;
+            continue;
+        }
+
+        let content = &after[after.find('>')? + 1..];
+        let end = content.find(&close)?;
+        rest = &content[end + close.len()..];
+        return Some(&content[..end]);
+    })
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 5: Supply Stacks ---</h2>
+<p>For example:</p>
+<pre><code>    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+</code></pre>
+<p>After the first move:</p>
+<pre><code>[D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+</code></pre>
+<p>Crates end up as <code><em>CMZ</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>BWNCQRMDB</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Moving <em>multiple crates</em> at once gives <code><em>MCD</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn examples_and_answers_are_extracted_per_part() {
+        let extracted = extract(PAGE);
+        assert_eq!(extracted.examples, vec!["    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\n"]);
+        assert_eq!(extracted.answers, vec![Some("CMZ".to_string()), Some("MCD".to_string())]);
+    }
+
+    #[test]
+    fn markup_inside_code_is_removed_and_decoded() {
+        let page = "<article><pre><code>a &lt;<em>b</em>&gt; &amp;c</code></pre><p>none</p></article>";
+        let extracted = extract(page);
+        assert_eq!(extracted.examples, vec!["a <b> &c"]);
+        assert_eq!(extracted.answers, vec![None]);
+    }
+
+    #[test]
+    fn similar_tag_names_are_not_confused() {
+        let html = "<em>no</em><emph>x</emph><em class=\"star\">yes</em>";
+        assert_eq!(elements(html, "em").collect::<Vec<_>>(), vec!["no", "yes"]);
+    }
+}
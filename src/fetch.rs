@@ -0,0 +1,210 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+pub const YEAR: usize = 2022;
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().build(),
+        }
+    }
+
+    pub fn from_env(base_url: Option<&str>) -> Result<Self> {
+        let session = env::var(SESSION_VAR).with_context(|| format!("{} is not set", SESSION_VAR))?;
+        let base_url = match base_url {
+            Some(url) => url.to_string(),
+            None => env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+        };
+
+        Ok(Client::new(&base_url, &session))
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, path)
+    }
+
+    pub fn download_input(&self, day: usize) -> Result<String> {
+        let url = self.url(&format!("{}/input", day));
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .context("error requesting input")?;
+
+        let body = response.into_string().context("error reading response body")?;
+        if body.trim().is_empty() {
+            bail!("received empty input from {}", url);
+        }
+
+        Ok(body)
+    }
+
+    pub fn submit_answer(&self, day: usize, part: usize, answer: &str) -> Result<String> {
+        let url = self.url(&format!("{}/answer", day));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .context("error submitting answer")?;
+
+        response.into_string().context("error reading response body")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// An existing non-empty input is never overwritten, so fetching again works offline.
+pub fn fetch_input(client: &Client, day: usize, dir: &Path) -> Result<Fetched> {
+    let path = dir.join(format!("day{:02}.txt", day));
+    if is_non_empty(&path) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.download_input(day)?;
+    fs::create_dir_all(dir).with_context(|| format!("error creating {}", dir.display()))?;
+    fs::write(&path, input).with_context(|| format!("error writing {}", path.display()))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+fn is_non_empty(path: &Path) -> bool {
+    fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false)
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    // Serves the given responses in order and hands back every request it received.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(v) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = v.trim().parse().unwrap();
+                    }
+                    request += &line;
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request += &String::from_utf8(content).unwrap();
+                sender.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc2022-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stub::{serve, temp_dir};
+    use super::*;
+
+    #[test]
+    fn downloads_and_caches_missing_input() {
+        let (url, requests) = serve(vec![(200, "1\n2\n")]);
+        let dir = temp_dir("fetch-missing");
+        let client = Client::new(&url, "secret");
+
+        let fetched = fetch_input(&client, 6, &dir).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("day06.txt")));
+        assert_eq!(fs::read_to_string(dir.join("day06.txt")).unwrap(), "1\n2\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/6/input HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+    }
+
+    #[test]
+    fn empty_cached_files_are_replaced() {
+        let (url, _requests) = serve(vec![(200, "abc\n")]);
+        let dir = temp_dir("fetch-empty");
+        fs::write(dir.join("day06.txt"), "").unwrap();
+
+        let fetched = fetch_input(&Client::new(&url, "secret"), 6, &dir).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("day06.txt")));
+        assert_eq!(fs::read_to_string(dir.join("day06.txt")).unwrap(), "abc\n");
+    }
+
+    #[test]
+    fn non_empty_files_are_never_overwritten() {
+        let dir = temp_dir("fetch-cached");
+        fs::write(dir.join("day01.txt"), "mine\n").unwrap();
+
+        // nothing listens on this address, so any request would fail
+        let client = Client::new("http://127.0.0.1:9", "secret");
+        let fetched = fetch_input(&client, 1, &dir).unwrap();
+        assert_eq!(fetched, Fetched::Cached(dir.join("day01.txt")));
+        assert_eq!(fs::read_to_string(dir.join("day01.txt")).unwrap(), "mine\n");
+    }
+
+    #[test]
+    fn failed_downloads_leave_no_file() {
+        let (url, _requests) = serve(vec![(404, "not found"), (200, "")]);
+        let dir = temp_dir("fetch-failed");
+        let client = Client::new(&url, "secret");
+
+        assert!(fetch_input(&client, 3, &dir).is_err());
+        assert!(fetch_input(&client, 3, &dir).is_err());
+        assert!(!dir.join("day03.txt").exists());
+    }
+}
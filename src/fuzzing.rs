@@ -0,0 +1,73 @@
+use std::{fmt::Debug, fmt::Display, str::FromStr};
+
+// Checks shared by the targets in `fuzz/`, so that a crashing input can be replayed as a unit test.
+
+pub fn check_parse<T: FromStr>(s: &str) {
+    let _ = s.parse::<T>();
+}
+
+// Whatever parses must print as text that parses back to the same value.
+pub fn check_round_trip<T>(s: &str)
+where
+    T: FromStr + Display + PartialEq + Debug,
+    T::Err: Display,
+{
+    let Ok(value) = s.parse::<T>() else { return };
+
+    let text = value.to_string();
+    match text.parse::<T>() {
+        Ok(again) => assert_eq!(again, value, "`{}` changed after printing it as `{}`", s, text),
+        Err(e) => panic!("`{}` printed as `{}`, which does not parse: {}", s, text, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day02, day03, day04, day05};
+
+    // Inputs that once crashed a parser or sit on the edge of what they accept.
+    const ODD_INPUTS: &[&str] = &[
+        "",
+        " ",
+        "\n",
+        "\r\n",
+        "a",
+        "é",
+        "aé",
+        "A Y",
+        "A  Y",
+        "ab1",
+        "1-2,3-4",
+        "18446744073709551616-1,1-1",
+        "1-2,3-4\n",
+        "move 1 from 2 to 1",
+        "move 0 from 0 to 0",
+        "move 99999999999999999999 from 1 to 2",
+        "[A]\n[B] [C]\n 1   2",
+        "    [A]\n 1   2",
+        "[A]\n",
+        "[é]\n 1",
+    ];
+
+    #[test]
+    fn odd_inputs_do_not_panic() {
+        for input in ODD_INPUTS {
+            check_parse::<day02::Shape>(input);
+            check_parse::<day02::Response>(input);
+            check_parse::<day02::Round>(input);
+            check_parse::<day05::Stacks>(input);
+
+            check_round_trip::<day03::Rucksack>(input);
+            check_round_trip::<day04::AssignmentSection>(input);
+            check_round_trip::<day04::SectionPair>(input);
+            check_round_trip::<day05::Move>(input);
+        }
+    }
+
+    #[test]
+    fn leading_zeros_survive_a_round_trip() {
+        check_round_trip::<day04::SectionPair>("01-2,3-04");
+        check_round_trip::<day05::Move>("move 01 from 002 to 3");
+    }
+}
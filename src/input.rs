@@ -0,0 +1,110 @@
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    Day { day: usize, variant: Option<String> },
+}
+
+impl InputSource {
+    pub fn day(day: usize) -> Self {
+        InputSource::Day { day, variant: None }
+    }
+
+    pub fn variant(day: usize, variant: &str) -> Self {
+        InputSource::Day { day, variant: Some(variant.to_string()) }
+    }
+
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        InputSource::Path(path.into())
+    }
+
+    // `-` is the usual command line spelling for stdin
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            _ => InputSource::path(arg),
+        }
+    }
+
+    pub fn resolve(&self) -> Option<PathBuf> {
+        match self {
+            InputSource::Path(p) => Some(p.clone()),
+            InputSource::Stdin => None,
+            InputSource::Day { .. } => self.resolve_in(&input_dir()),
+        }
+    }
+
+    fn resolve_in(&self, dir: &Path) -> Option<PathBuf> {
+        match self {
+            InputSource::Day { day, variant: None } => Some(dir.join(format!("day{:02}.txt", day))),
+            InputSource::Day { day, variant: Some(v) } => Some(dir.join(format!("day{:02}.{}.txt", day, v))),
+            _ => self.resolve(),
+        }
+    }
+
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, io::Error> {
+        match self.resolve() {
+            Some(path) => {
+                let file = File::open(&path)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            None => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    pub fn read(&self) -> Result<Vec<String>, io::Error> {
+        self.reader()?.lines().collect()
+    }
+}
+
+// Falls back to the crate's own inputs folder, so reading works regardless of the working directory.
+pub fn input_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_sources_resolve_to_padded_file_names() {
+        let dir = Path::new("somewhere");
+        assert_eq!(InputSource::day(5).resolve_in(dir), Some(dir.join("day05.txt")));
+        assert_eq!(InputSource::day(12).resolve_in(dir), Some(dir.join("day12.txt")));
+        assert_eq!(InputSource::variant(5, "example").resolve_in(dir), Some(dir.join("day05.example.txt")));
+    }
+
+    #[test]
+    fn explicit_sources_ignore_the_input_dir() {
+        let dir = Path::new("somewhere");
+        assert_eq!(InputSource::path("a/b.txt").resolve_in(dir), Some(PathBuf::from("a/b.txt")));
+        assert_eq!(InputSource::Stdin.resolve_in(dir), None);
+    }
+
+    #[test]
+    fn arguments_map_to_sources() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(InputSource::from_arg("x.txt"), InputSource::path("x.txt"));
+    }
+
+    #[test]
+    fn default_input_dir_is_independent_of_working_directory() {
+        if env::var_os(INPUT_DIR_VAR).is_none() {
+            assert!(input_dir().is_absolute());
+            assert!(InputSource::day(1).read().is_ok());
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::{Add, Sub};
+
+pub trait Integer: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// An inclusive range `start..=end`, never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn point(value: T) -> Self {
+        Interval { start: value, end: value }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    // The number of values, None if it does not fit into `T`.
+    pub fn len(&self) -> Option<T> {
+        self.end.checked_sub(self.start)?.checked_add(T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        other.start <= self.end && self.start <= other.end
+    }
+
+    // Whether the two leave no gap between them, so that their union is a single interval.
+    pub fn touches(&self, other: &Self) -> bool {
+        let follows = |a: &Self, b: &Self| a.end < b.start && a.end + T::ONE == b.start;
+        self.overlaps(other) || follows(self, other) || follows(other, self)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        self.touches(other).then(|| self.hull(other))
+    }
+
+    // The smallest interval covering both, including any gap in between.
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval { start: self.start.min(other.start), end: self.end.max(other.end) }
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// Disjoint intervals kept sorted, with overlapping and adjacent ones merged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { intervals: Vec::new() }
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut merged = interval;
+        let mut placed = false;
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+
+        for i in self.intervals.drain(..) {
+            if i.touches(&merged) {
+                merged = merged.hull(&i);
+            } else if i.end < merged.start {
+                intervals.push(i);
+            } else {
+                if !placed {
+                    intervals.push(merged);
+                    placed = true;
+                }
+                intervals.push(i);
+            }
+        }
+
+        if !placed {
+            intervals.push(merged);
+        }
+        self.intervals = intervals;
+    }
+
+    // The number of values covered, None if it does not fit into `T`.
+    pub fn coverage(&self) -> Option<T> {
+        self.intervals.iter().try_fold(T::ZERO, |sum, i| sum.checked_add(i.len()?))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.find(value).is_some()
+    }
+
+    // The interval holding `value`.
+    pub fn find(&self, value: T) -> Option<&Interval<T>> {
+        let index = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(index).filter(|i| i.contains(value))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for &i in &other.intervals {
+            result.insert(i);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            intervals.extend(x.intersection(y));
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        RangeSet { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+
+        for i in &self.intervals {
+            let mut rest = Some(*i);
+            for cut in other.intervals.iter().filter(|c| c.overlaps(i)) {
+                let Some(r) = rest else { break };
+                if cut.start > r.start {
+                    intervals.push(Interval { start: r.start, end: cut.start - T::ONE });
+                }
+                rest = (cut.end < r.end).then(|| Interval { start: cut.end + T::ONE, end: r.end });
+            }
+            intervals.extend(rest);
+        }
+
+        RangeSet { intervals }
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for RangeSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        RangeSet { intervals: vec![interval] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn interval(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i32, i32)]) -> RangeSet<i32> {
+        intervals.iter().map(|&(s, e)| interval(s, e)).collect()
+    }
+
+    #[test]
+    fn intervals_are_never_empty() {
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(Interval::new(2, 2), Some(Interval::point(2)));
+        assert_eq!(interval(-2, 2).len(), Some(5));
+    }
+
+    #[test]
+    fn interval_relations() {
+        let a = interval(5, 10);
+
+        assert!(a.contains_interval(&interval(6, 8)));
+        assert!(!a.contains_interval(&interval(4, 8)));
+        assert!(a.overlaps(&interval(10, 12)));
+        assert!(!a.overlaps(&interval(11, 12)));
+        assert!(a.touches(&interval(11, 12)));
+        assert!(a.touches(&interval(1, 4)));
+        assert!(!a.touches(&interval(12, 13)));
+
+        assert_eq!(a.intersection(&interval(8, 20)), Some(interval(8, 10)));
+        assert_eq!(a.intersection(&interval(11, 20)), None);
+        assert_eq!(a.union(&interval(11, 20)), Some(interval(5, 20)));
+        assert_eq!(a.union(&interval(12, 20)), None);
+    }
+
+    #[test]
+    fn touching_at_the_type_limits_does_not_overflow() {
+        let top = Interval::new(250u8, 255).unwrap();
+        assert!(!top.touches(&Interval::new(0, 5).unwrap()));
+        assert!(top.touches(&Interval::new(0, 249).unwrap()));
+    }
+
+    #[test]
+    fn signed_extremes_do_not_overflow() {
+        let (low, high) = (interval(i32::MIN, i32::MIN + 1), interval(i32::MAX - 1, i32::MAX));
+        assert!(!low.touches(&high) && !high.touches(&low));
+        assert_eq!(set(&[(i32::MIN, i32::MIN + 1), (i32::MAX - 1, i32::MAX)]).intervals(), &[low, high]);
+
+        assert_eq!(interval(i32::MIN, i32::MAX).len(), None);
+        assert_eq!(interval(i32::MIN, -2).len(), Some(i32::MAX));
+        assert_eq!(interval(i32::MIN, -1).len(), None);
+        assert_eq!(set(&[(i32::MIN, -3), (0, 0)]).coverage(), Some(i32::MAX));
+        assert_eq!(set(&[(i32::MIN, -3), (0, 1)]).coverage(), None);
+        assert_eq!(Interval::new(0u8, 255).unwrap().len(), None);
+    }
+
+    #[test]
+    fn inserting_merges_overlapping_and_adjacent_intervals() {
+        let ranges = set(&[(10, 14), (1, 3), (4, 5), (20, 22), (12, 18)]);
+        assert_eq!(ranges.intervals(), &[interval(1, 5), interval(10, 18), interval(20, 22)]);
+        assert_eq!(ranges.coverage(), Some(17));
+        assert!(ranges.contains(15));
+        assert!(!ranges.contains(19));
+        assert_eq!(ranges.find(21), Some(&interval(20, 22)));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(5, 22), (28, 40)]);
+
+        assert_eq!(a.union(&b), set(&[(1, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 22), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(1, 4), (23, 27)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19), (31, 40)]));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    fn points(ranges: &RangeSet<i32>) -> BTreeSet<i32> {
+        ranges.intervals().iter().flat_map(|i| i.start()..=i.end()).collect()
+    }
+
+    fn range_sets() -> impl Strategy<Value = RangeSet<i32>> {
+        let intervals = (-20..20i32, 0..6i32).prop_map(|(s, l)| interval(s, s + l));
+        prop::collection::vec(intervals, 0..6).prop_map(|i| i.into_iter().collect())
+    }
+
+    proptest! {
+        #[test]
+        fn set_operations_match_point_sets(a in range_sets(), b in range_sets()) {
+            let (x, y) = (points(&a), points(&b));
+
+            prop_assert_eq!(points(&a.union(&b)), &x | &y);
+            prop_assert_eq!(points(&a.intersection(&b)), &x & &y);
+            prop_assert_eq!(points(&a.difference(&b)), &x - &y);
+            prop_assert_eq!(a.coverage(), Some(x.len() as i32));
+        }
+
+        #[test]
+        fn intervals_stay_sorted_and_apart(a in range_sets(), b in range_sets()) {
+            for set in [a.union(&b), a.intersection(&b), a.difference(&b)] {
+                for pair in set.intervals().windows(2) {
+                    prop_assert!(pair[0].end() < pair[1].start() && !pair[0].touches(&pair[1]));
+                }
+            }
+        }
+    }
+}
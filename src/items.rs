@@ -0,0 +1,174 @@
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{BitAnd, BitOr};
+
+// The priority of a rucksack item, `a-z` map to 1..=26 and `A-Z` to 27..=52.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        _ => (b'A' + (priority - 27) as u8) as char,
+    }
+}
+
+// A set of items with the bit of every item's priority set.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns false for characters that are not items.
+    pub fn insert(&mut self, item: char) -> bool {
+        let Some(p) = priority(item) else { return false };
+        self.0 |= 1 << p;
+        true
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).is_some_and(|p| self.0 & (1 << p) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        ItemSet(self.0 | other.0)
+    }
+
+    // The items found in every one of the sets, nothing if there are none.
+    pub fn common(sets: impl IntoIterator<Item = ItemSet>) -> Self {
+        sets.into_iter().reduce(|a, b| a & b).unwrap_or_default()
+    }
+
+    // Items from the lowest priority to the highest.
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            let p = bits.trailing_zeros();
+            (bits != 0).then(|| {
+                bits &= bits - 1;
+                item(p)
+            })
+        })
+    }
+
+    pub fn priorities(&self) -> impl Iterator<Item = u32> {
+        self.iter().filter_map(priority)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(&other)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(&other)
+    }
+}
+
+// Characters that are not items are left out.
+impl From<&str> for ItemSet {
+    fn from(s: &str) -> Self {
+        s.chars().collect()
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = ItemSet::new();
+        for c in iter {
+            set.insert(c);
+        }
+        set
+    }
+}
+
+impl Debug for ItemSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn priorities_cover_both_alphabets() {
+        assert_eq!(priority('a'), Some(1));
+        assert_eq!(priority('z'), Some(26));
+        assert_eq!(priority('A'), Some(27));
+        assert_eq!(priority('Z'), Some(52));
+        assert_eq!(priority('0'), None);
+        assert!((1..=52).all(|p| priority(item(p)) == Some(p)));
+    }
+
+    #[test]
+    fn sets_iterate_in_priority_order() {
+        let set = ItemSet::from("ZbAza");
+
+        assert_eq!(set.iter().collect::<String>(), "abzAZ");
+        assert_eq!(set.priorities().collect::<Vec<_>>(), vec![1, 2, 26, 27, 52]);
+        assert_eq!(set.len(), 5);
+        assert!(set.contains('A') && !set.contains('B'));
+    }
+
+    #[test]
+    fn non_items_are_ignored() {
+        let mut set = ItemSet::from("a1 -é");
+        assert_eq!(set.iter().collect::<String>(), "a");
+        assert!(!set.insert('?'));
+        assert!(set.insert('b'));
+    }
+
+    #[test]
+    fn common_items_of_many_sets() {
+        let sets = ["vJrwpWtwJgWrhcsFMMfFFhFp", "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", "PmmdzqPrVvPwwTWBwg"];
+
+        assert_eq!(ItemSet::common(sets.map(ItemSet::from)).iter().collect::<String>(), "r");
+        assert_eq!(ItemSet::from("abc") & ItemSet::from("bcd"), ItemSet::from("cb"));
+        assert_eq!(ItemSet::from("ab") | ItemSet::from("B"), ItemSet::from("Bab"));
+        assert!(ItemSet::common([]).is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn sets_behave_like_sets_of_chars(a in "[a-zA-Z]{0,20}", b in "[a-zA-Z]{0,20}") {
+            let (x, y) = (a.chars().collect::<BTreeSet<_>>(), b.chars().collect::<BTreeSet<_>>());
+            let chars = |s: ItemSet| s.iter().collect::<BTreeSet<_>>();
+
+            prop_assert_eq!(chars(ItemSet::from(a.as_str())), x.clone());
+            prop_assert_eq!(chars(ItemSet::from(a.as_str()) & ItemSet::from(b.as_str())), &x & &y);
+            prop_assert_eq!(chars(ItemSet::from(a.as_str()) | ItemSet::from(b.as_str())), &x | &y);
+        }
+    }
+}
@@ -1,22 +1,134 @@
 use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
+    convert::Infallible,
+    io::{self, BufRead, Lines},
     str::FromStr,
+    time::{Duration, Instant},
 };
 
+extern crate self as aoc2022;
+
+pub mod answers;
+pub mod bench;
+pub mod buffer;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod diagram;
+pub mod error;
+pub mod extract;
+pub mod fetch;
+pub mod fuzzing;
+pub mod input;
+pub mod interval;
+pub mod items;
+pub mod output;
+pub mod parallel;
+pub mod policy;
+pub mod submit;
+pub mod testing;
+pub mod topk;
+pub mod watch;
+pub mod window;
+
+pub use aoc2022_derive::FromStrNom;
+pub use buffer::{InputBuffer, ParseBorrowed};
+pub use error::{ParseError, ParseErrors};
+pub use input::InputSource;
+pub use nom;
+pub use output::Answer;
+pub use policy::Policy;
+
+pub trait Solution {
+    const DAY: usize;
+    const PART: usize;
+
+    type Input;
+    type Output: Into<Answer>;
+
+    fn parse(lines: Vec<String>) -> anyhow::Result<Self::Input>;
+    fn solve(input: &Self::Input) -> anyhow::Result<Self::Output>;
+}
+
+pub trait Puzzle: Sync {
+    fn day(&self) -> usize;
+    fn part(&self) -> usize;
+    fn run_timed(&self, lines: Vec<String>) -> anyhow::Result<Timed>;
+}
+
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn part(&self) -> usize {
+        S::PART
+    }
+
+    fn run_timed(&self, lines: Vec<String>) -> anyhow::Result<Timed> {
+        let start = Instant::now();
+        let input = S::parse(lines)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let output = S::solve(&input)?;
+        let solve = start.elapsed();
+
+        Ok(Timed { answer: output.into(), parse, solve })
+    }
+}
+
+static PUZZLES: &[&dyn Puzzle] = &[
+    &day01::Part1,
+    &day01::Part2,
+    &day02::Part1,
+    &day02::Part2,
+    &day03::Part1,
+    &day03::Part2,
+    &day04::Part1,
+    &day04::Part2,
+    &day05::Part1,
+    &day05::Part2,
+    &day06::Part1,
+    &day06::Part2,
+];
+
+pub fn puzzles() -> &'static [&'static dyn Puzzle] {
+    PUZZLES
+}
+
+pub fn find_puzzles(day: Option<usize>, part: Option<usize>) -> Vec<&'static dyn Puzzle> {
+    PUZZLES
+        .iter()
+        .filter(|p| day.is_none_or(|d| p.day() == d))
+        .filter(|p| part.is_none_or(|n| p.part() == n))
+        .copied()
+        .collect()
+}
+
 pub fn read(day: usize) -> Result<Vec<String>, io::Error> {
-    let path = format!("inputs/day{:02}.txt", day);
-    let file = File::open(path)?;
-    let file = BufReader::new(file);
-    file.lines().collect()
+    InputSource::day(day).read()
 }
 
 pub fn pack(lines: Vec<String>) -> Vec<Vec<String>> {
+    pack_lines(lines)
+}
+
+// `pack` over borrowed as well as owned lines
+pub(crate) fn pack_lines<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Vec<Vec<S>> {
     let mut result = Vec::new();
 
     let mut pack = Vec::new();
     for l in lines {
-        if !l.is_empty() {
+        if !l.as_ref().is_empty() {
             pack.push(l);
             continue;
         }
@@ -32,30 +144,247 @@ pub fn pack(lines: Vec<String>) -> Vec<Vec<String>> {
     result
 }
 
-pub fn convert<T: FromStr>(lines: Vec<String>) -> Result<Vec<T>, <T as FromStr>::Err> {
-    lines.iter().map(|l| l.parse()).collect()
+pub fn pack_reader<R: BufRead>(reader: R) -> Packs<R> {
+    Packs { lines: reader.lines() }
+}
+
+pub struct Packs<R> {
+    lines: Lines<R>,
+}
+
+impl<R: BufRead> Iterator for Packs<R> {
+    type Item = Result<Vec<String>, io::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut pack = Vec::new();
+
+        for l in self.lines.by_ref() {
+            match l {
+                Ok(l) if l.is_empty() => return Some(Ok(pack)),
+                Ok(l) => pack.push(l),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        if pack.is_empty() {
+            return None;
+        }
+
+        Some(Ok(pack))
+    }
+}
+
+pub fn convert<T>(lines: Vec<String>) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    lines.iter().enumerate().map(|(i, l)| parse_line(i, l)).collect()
+}
+
+pub fn convert_pack<T>(packs: Vec<Vec<String>>) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    pack_offsets(&packs)
+        .into_iter()
+        .zip(packs)
+        .enumerate()
+        .map(|(i, (offset, p))| convert::<T>(p).map_err(|e| e.offset_lines(offset).in_pack(i)))
+        .collect()
+}
+
+pub struct Validated<T> {
+    pub values: T,
+    pub errors: ParseErrors,
+}
+
+impl<T> Validated<T> {
+    pub fn into_result(self) -> Result<T, ParseErrors> {
+        match self.errors.is_empty() {
+            true => Ok(self.values),
+            false => Err(self.errors),
+        }
+    }
+}
+
+pub fn validate<T>(lines: Vec<String>) -> Validated<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+
+    for (i, l) in lines.iter().enumerate() {
+        match parse_line(i, l) {
+            Ok(v) => values.push(v),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    Validated { values, errors: ParseErrors(errors) }
+}
+
+pub fn validate_pack<T>(packs: Vec<Vec<String>>) -> Validated<Vec<Vec<T>>>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    validate_packs(packs.into_iter().map(Ok)).unwrap_or_else(|e: Infallible| match e {})
+}
+
+// `validate_pack` over packs streamed with `pack_reader`
+pub fn validate_pack_reader<T, R>(reader: R) -> Result<Validated<Vec<Vec<T>>>, io::Error>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+    R: BufRead,
+{
+    validate_packs(pack_reader(reader))
+}
+
+fn validate_packs<T, E>(packs: impl Iterator<Item = Result<Vec<String>, E>>) -> Result<Validated<Vec<Vec<T>>>, E>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    let mut offset = 0;
+
+    for (i, p) in packs.enumerate() {
+        let p = p?;
+        let lines = p.len();
+        let validated = validate::<T>(p);
+        values.push(validated.values);
+        errors.extend(validated.errors.0.into_iter().map(|e| e.offset_lines(offset).in_pack(i)));
+        offset += lines + 1;
+    }
+
+    Ok(Validated { values, errors: ParseErrors(errors) })
+}
+
+fn parse_line<T>(index: usize, line: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    line.parse().map_err(|e: T::Err| e.into().with_snippet(line).offset_lines(index))
 }
 
-pub fn convert_pack<T: FromStr>(packs: Vec<Vec<String>>) -> Result<Vec<Vec<T>>, <T as FromStr>::Err> {
-    packs.into_iter().map(convert::<T>).collect()
+// every pack is followed by exactly one blank line in the original input
+pub(crate) fn pack_offsets<S>(packs: &[Vec<S>]) -> Vec<usize> {
+    packs
+        .iter()
+        .scan(0, |offset, p| {
+            let current = *offset;
+            *offset += p.len() + 1;
+            Some(current)
+        })
+        .collect()
 }
 
-#[macro_export] macro_rules! impl_from_str {
-    ($impl_type:ty) => {
-       impl std::str::FromStr for $impl_type {
-           type Err = nom::error::Error<String>;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn pack_reader_matches_pack() {
+        let cases = ["1\n2\n\n3\n", "1\n\n\n2", "", "\n", "1\n2\n\n"];
+
+        for input in cases {
+            let streamed = pack_reader(input.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+            let collected = pack(input.lines().map(String::from).collect());
+            assert_eq!(streamed, collected, "input {:?}", input);
+        }
+    }
+
+    #[derive(Debug, PartialEq, FromStrNom)]
+    #[nom(partial)]
+    struct Number(u64);
+
+    impl Number {
+        fn parse(s: &str) -> nom::IResult<&str, Number> {
+            nom::combinator::map(nom::character::complete::u64, Number)(s)
+        }
+    }
+
+    #[derive(Debug, PartialEq, FromStrNom)]
+    #[nom(parser = parse_pair, trim)]
+    struct Pair(u64, u64);
+
+    impl Pair {
+        fn parse_pair(s: &str) -> nom::IResult<&str, Pair> {
+            let comma = nom::character::complete::char(',');
+            let parser = nom::sequence::separated_pair(Number::parse, comma, Number::parse);
+            nom::combinator::map(parser, |(a, b)| Pair(a.0, b.0))(s)
+        }
+    }
+
+    #[test]
+    fn derived_from_str_uses_the_selected_parser() {
+        assert_eq!("12".parse::<Number>().unwrap(), Number(12));
+        assert_eq!(Number::try_from("7").unwrap(), Number(7));
+        assert_eq!(Pair::try_from("1,2").unwrap(), Pair(1, 2));
+        assert_eq!(convert::<Pair>(lines(&["3,4"])).unwrap(), vec![Pair(3, 4)]);
+    }
+
+    #[test]
+    fn derived_from_str_applies_the_policy() {
+        assert_eq!("12 and more".parse::<Number>().unwrap(), Number(12));
+        assert_eq!(" 1,2\n".parse::<Pair>().unwrap(), Pair(1, 2));
+
+        let error = "1,2 and more".parse::<Pair>().unwrap_err();
+        assert_eq!(error.column, Some(4));
+        assert_eq!(error.expected, "Pair (End of file)");
+    }
+
+    #[test]
+    fn convert_reports_the_failing_line() {
+        let error = convert::<i32>(lines(&["1", "2", "x3"])).unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.snippet.as_deref(), Some("x3"));
+    }
+
+    #[test]
+    fn convert_pack_counts_lines_across_packs() {
+        let packs = pack(lines(&["1", "2", "", "3", "", "4", "five"]));
+        let error = convert_pack::<i32>(packs).unwrap_err();
+        assert_eq!(error.line, 7);
+        assert_eq!(error.pack, Some(2));
+        assert_eq!(error.snippet.as_deref(), Some("five"));
+    }
+
+    #[test]
+    fn validate_collects_every_error() {
+        let validated = validate::<i32>(lines(&["1", "a", "3", "b"]));
+        assert_eq!(validated.values, vec![1, 3]);
+
+        let lines = validated.errors.iter().map(|e| e.line).collect::<Vec<_>>();
+        assert_eq!(lines, vec![2, 4]);
+        assert!(validated.into_result().is_err());
+    }
+
+    #[test]
+    fn validate_pack_keeps_pack_positions() {
+        let packs = pack(lines(&["1", "x", "", "3", "", "y", "6"]));
+        let validated = validate_pack::<i32>(packs);
+        assert_eq!(validated.values, vec![vec![1], vec![3], vec![6]]);
+
+        let positions = validated.errors.iter().map(|e| (e.pack, e.line)).collect::<Vec<_>>();
+        assert_eq!(positions, vec![(Some(0), 2), (Some(2), 6)]);
+    }
 
-           fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-               let result = <$impl_type>::parse(s);
-               let finished = nom::Finish::finish(result);
-               match finished {
-                   Ok((_, v)) => Ok(v),
-                   Err(nom::error::Error { input, code }) => Err(nom::error::Error {
-                       input: input.to_string(),
-                       code,
-                   }),
-               }
-           }
-       }
+    #[test]
+    fn validate_without_errors_converts() {
+        let packs = pack(lines(&["1", "", "2"]));
+        let values = validate_pack::<i32>(packs).into_result().unwrap();
+        assert_eq!(values, vec![vec![1], vec![2]]);
     }
 }
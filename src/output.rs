@@ -0,0 +1,303 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::anyhow;
+use serde::Serialize;
+
+// Answers are numbers for most days, but e.g. day 05 spells out crate letters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{}", i),
+            Answer::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+// Values outside of `i64` keep all their digits as text.
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    match i64::try_from(value) {
+                        Ok(i) => Answer::Int(i),
+                        Err(_) => Answer::Text(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, u32, i64, u64, usize, i128, u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Plain,
+    Json,
+    JsonLines,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "json-lines" | "jsonl" => Ok(Format::JsonLines),
+            _ => Err(anyhow!("expected `plain`, `json` or `json-lines`, got `{}`", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Failed,
+    Panicked,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Status::Ok => "ok",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: usize,
+    pub part: usize,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub timings: Timings,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct Timings {
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Duration,
+    #[serde(rename = "solve_ns", serialize_with = "nanos")]
+    pub solve: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+fn nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+impl Record {
+    pub fn answer(day: usize, part: usize, answer: Answer, timings: Timings) -> Self {
+        Record { day, part, status: Status::Ok, answer: Some(answer), error: None, timings }
+    }
+
+    pub fn error(day: usize, part: usize, error: &anyhow::Error) -> Self {
+        let error = Some(format!("{:#}", error));
+        Record { day, part, status: Status::Failed, answer: None, error, timings: Timings::default() }
+    }
+
+    pub fn panicked(day: usize, part: usize, message: &str) -> Self {
+        let error = Some(format!("panicked: {}", message));
+        Record { day, part, status: Status::Panicked, answer: None, error, timings: Timings::default() }
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "day {:02} part {}: ", self.day, self.part)?;
+        match (&self.answer, &self.error) {
+            (Some(answer), _) => write!(f, "{} ({:.2?})", answer, self.timings.total()),
+            (None, Some(error)) => write!(f, "{}", error),
+            (None, None) => write!(f, "no answer"),
+        }
+    }
+}
+
+impl Format {
+    pub fn write(&self, out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+        match self {
+            Format::Plain => {
+                for record in records {
+                    writeln!(out, "{}", record)?;
+                }
+            }
+            Format::Json => {
+                serde_json::to_writer_pretty(&mut *out, records)?;
+                writeln!(out)?;
+            }
+            Format::JsonLines => {
+                for record in records {
+                    serde_json::to_writer(&mut *out, record)?;
+                    writeln!(out)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn write_table(out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+    let answers = records.iter().map(|r| r.answer.as_ref().map(|a| a.to_string()).unwrap_or_default());
+    let answers = answers.collect::<Vec<_>>();
+    let width = answers.iter().map(|a| a.chars().count()).max().unwrap_or(0).max("answer".len());
+
+    writeln!(out, "{:<14} {:<8} {:<width$} {:>12} {:>12} {:>12}", "solution", "status", "answer", "parse", "solve", "total")?;
+    for (record, answer) in records.iter().zip(&answers) {
+        writeln!(
+            out,
+            "{:<14} {:<8} {:<width$} {:>12} {:>12} {:>12}",
+            format!("day {:02} part {}", record.day, record.part),
+            record.status,
+            answer,
+            format!("{:.2?}", record.timings.parse),
+            format!("{:.2?}", record.timings.solve),
+            format!("{:.2?}", record.timings.total()),
+        )?;
+    }
+
+    let count = |status| records.iter().filter(|r| r.status == status).count();
+    let total = records.iter().map(|r| r.timings.total()).sum::<Duration>();
+    writeln!(
+        out,
+        "{} ok, {} failed, {} panicked, {:.2?} in total",
+        count(Status::Ok),
+        count(Status::Failed),
+        count(Status::Panicked),
+        total,
+    )?;
+
+    for record in records.iter().filter(|r| r.status != Status::Ok) {
+        if let Some(error) = &record.error {
+            writeln!(out, "day {:02} part {}: {}", record.day, record.part, error)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let timings = Timings { parse: Duration::from_micros(1), solve: Duration::from_micros(2) };
+        vec![
+            Record::answer(1, 1, 24000.into(), timings),
+            Record::answer(5, 1, "CMZ".into(), timings),
+            Record::error(6, 1, &anyhow!("not enough characters")),
+        ]
+    }
+
+    #[test]
+    fn large_integers_are_not_truncated() {
+        assert_eq!(Answer::from(u64::MAX), Answer::Text("18446744073709551615".to_string()));
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Int(i64::MAX));
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = Vec::new();
+        format.write(&mut out, &records()).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn answers_keep_their_type() {
+        assert_eq!(Answer::from(42usize), Answer::Int(42));
+        assert_eq!(Answer::from("CMZ".to_string()), Answer::Text("CMZ".to_string()));
+        assert_eq!(Answer::Int(-3).to_string(), "-3");
+        assert_eq!(Answer::Text("CMZ".to_string()).to_string(), "CMZ");
+    }
+
+    #[test]
+    fn formats_are_parsed_by_name() {
+        assert_eq!("plain".parse::<Format>().unwrap(), Format::Plain);
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert_eq!("json-lines".parse::<Format>().unwrap(), Format::JsonLines);
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn plain_output_is_one_line_per_record() {
+        let expected = "day 01 part 1: 24000 (3.00µs)\nday 05 part 1: CMZ (3.00µs)\nday 06 part 1: not enough characters\n";
+        assert_eq!(render(Format::Plain), expected);
+    }
+
+    #[test]
+    fn json_lines_output_is_one_object_per_line() {
+        let output = render(Format::JsonLines);
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], r#"{"day":1,"part":1,"status":"ok","answer":24000,"timings":{"parse_ns":1000,"solve_ns":2000}}"#);
+        assert_eq!(lines[1], r#"{"day":5,"part":1,"status":"ok","answer":"CMZ","timings":{"parse_ns":1000,"solve_ns":2000}}"#);
+        assert_eq!(lines[2], r#"{"day":6,"part":1,"status":"failed","error":"not enough characters","timings":{"parse_ns":0,"solve_ns":0}}"#);
+    }
+
+    #[test]
+    fn tables_summarise_every_record() {
+        let mut records = records();
+        records.push(Record::panicked(3, 1, "internal error: entered unreachable code"));
+
+        let mut out = Vec::new();
+        write_table(&mut out, &records).unwrap();
+        let table = String::from_utf8(out).unwrap();
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0].split_whitespace().collect::<Vec<_>>(), ["solution", "status", "answer", "parse", "solve", "total"]);
+        assert!(lines[2].starts_with("day 05 part 1  ok       CMZ"));
+        assert!(lines[4].starts_with("day 03 part 1  panicked"));
+        assert_eq!(lines[5], "2 ok, 1 failed, 1 panicked, 6.00µs in total");
+        assert_eq!(lines[6], "day 06 part 1: not enough characters");
+        assert_eq!(lines[7], "day 03 part 1: panicked: internal error: entered unreachable code");
+    }
+
+    #[test]
+    fn json_output_is_an_array() {
+        let value: serde_json::Value = serde_json::from_str(&render(Format::Json)).unwrap();
+        let records = value.as_array().unwrap();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0]["answer"], 24000);
+        assert_eq!(records[1]["answer"], "CMZ");
+    }
+}
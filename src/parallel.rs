@@ -0,0 +1,119 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Mutex},
+    thread,
+};
+
+use crate::output::{Record, Timings};
+use crate::Puzzle;
+
+pub struct Job {
+    pub puzzle: &'static dyn Puzzle,
+    pub lines: Vec<String>,
+}
+
+// Runs a single solution, turning a panic into a failed record instead of unwinding further.
+pub fn run_isolated(puzzle: &dyn Puzzle, lines: Vec<String>) -> Record {
+    let (day, part) = (puzzle.day(), puzzle.part());
+
+    match panic::catch_unwind(AssertUnwindSafe(|| puzzle.run_timed(lines))) {
+        Ok(Ok(timed)) => Record::answer(day, part, timed.answer, Timings { parse: timed.parse, solve: timed.solve }),
+        Ok(Err(e)) => Record::error(day, part, &e),
+        Err(payload) => Record::panicked(day, part, &panic_message(payload.as_ref())),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(s), _) => s.to_string(),
+        (_, Some(s)) => s.clone(),
+        _ => "unknown panic payload".to_string(),
+    }
+}
+
+// Spreads the jobs over `threads` workers; the records come back in the order of the jobs.
+pub fn run_parallel(jobs: Vec<Job>, threads: usize) -> Vec<Record> {
+    let count = jobs.len();
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, count.max(1)) {
+            let sender = sender.clone();
+            let queue = &queue;
+
+            scope.spawn(move || loop {
+                // the lock is released before the job runs, so a panic can't poison it
+                let next = queue.lock().unwrap().next();
+                let Some((i, job)) = next else { break };
+
+                if sender.send((i, run_isolated(job.puzzle, job.lines))).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut records = receiver.into_iter().collect::<Vec<_>>();
+    records.sort_by_key(|(i, _)| *i);
+    records.into_iter().map(|(_, r)| r).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Status;
+    use crate::Solution;
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: usize = 3;
+        const PART: usize = 1;
+
+        type Input = ();
+        type Output = u32;
+
+        fn parse(_: Vec<String>) -> anyhow::Result<Self::Input> {
+            Ok(())
+        }
+
+        fn solve(_: &Self::Input) -> anyhow::Result<Self::Output> {
+            unreachable!("no duplicate item")
+        }
+    }
+
+    fn job(puzzle: &'static dyn Puzzle, input: &str) -> Job {
+        Job { puzzle, lines: input.lines().map(String::from).collect() }
+    }
+
+    #[test]
+    fn panics_are_isolated() {
+        let record = run_isolated(&Panics, Vec::new());
+        assert_eq!(record.status, Status::Panicked);
+        assert_eq!(record.error.as_deref(), Some("panicked: internal error: entered unreachable code: no duplicate item"));
+    }
+
+    #[test]
+    fn records_keep_the_job_order() {
+        let jobs = vec![
+            job(&crate::day06::Part1, "mjqjpqmgbljsphdztnvjfqwrcgsmlb"),
+            job(&Panics, ""),
+            job(&crate::day06::Part2, "abc"),
+            job(&crate::day01::Part1, "1000\n\n2000"),
+        ];
+
+        let records = run_parallel(jobs, 3);
+        let summary = records.iter().map(|r| (r.day, r.part, r.status)).collect::<Vec<_>>();
+        assert_eq!(summary, vec![(6, 1, Status::Ok), (3, 1, Status::Panicked), (6, 2, Status::Failed), (1, 1, Status::Ok)]);
+        assert_eq!(records[0].answer, Some(7.into()));
+        assert_eq!(records[3].answer, Some(2000.into()));
+    }
+
+    #[test]
+    fn no_jobs_give_no_records() {
+        assert!(run_parallel(Vec::new(), 4).is_empty());
+    }
+}
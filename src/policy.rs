@@ -0,0 +1,129 @@
+use std::borrow::Cow;
+
+use nom::{combinator::all_consuming, IResult};
+
+use crate::{error::finish, ParseError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Policy {
+    trim: bool,
+    crlf: bool,
+    partial: bool,
+}
+
+impl Policy {
+    pub fn strict() -> Self {
+        Policy::default()
+    }
+
+    // ignore whitespace around the whole input
+    pub fn trim(self) -> Self {
+        Policy { trim: true, ..self }
+    }
+
+    // treat `\r\n` line endings like `\n`
+    pub fn crlf(self) -> Self {
+        Policy { crlf: true, ..self }
+    }
+
+    // accept input the parser leaves unconsumed
+    pub fn partial(self) -> Self {
+        Policy { partial: true, ..self }
+    }
+
+    pub fn parse<T, P>(&self, s: &str, mut parser: P) -> Result<T, ParseError>
+    where
+        P: FnMut(&str) -> IResult<&str, T>,
+    {
+        let base = match self.crlf && s.contains('\r') {
+            true => Cow::Owned(s.replace("\r\n", "\n")),
+            false => Cow::Borrowed(s),
+        };
+
+        let input = match self.trim {
+            true => base.trim(),
+            false => &base,
+        };
+
+        let result = match self.partial {
+            true => parser(input),
+            false => all_consuming(&mut parser)(input),
+        };
+
+        finish(input, result).map_err(|e| shift(e, &base, input))
+    }
+}
+
+// Moves an error found in `input`, a trimmed slice of `base`, back to its position in `base`.
+fn shift(mut error: ParseError, base: &str, input: &str) -> ParseError {
+    let leading = &base[..base.len() - base.trim_start().len()];
+    let lines = leading.matches('\n').count();
+    let line_start = leading.rfind('\n').map_or(0, |i| i + 1);
+
+    if error.line == 1 {
+        error.column = error.column.map(|c| c + leading[line_start..].chars().count());
+    }
+    error.line += lines;
+
+    if input.len() != base.len() {
+        error.snippet = base.lines().nth(error.line - 1).map(String::from);
+    }
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::{char, u64};
+    use nom::multi::separated_list1;
+
+    use super::*;
+
+    fn numbers(s: &str) -> IResult<&str, Vec<u64>> {
+        separated_list1(char('\n'), u64)(s)
+    }
+
+    #[test]
+    fn strict_policy_rejects_trailing_input() {
+        assert_eq!(Policy::strict().parse("1\n2", numbers).unwrap(), vec![1, 2]);
+
+        let error = Policy::strict().parse("1\n2 ", numbers).unwrap_err();
+        assert_eq!((error.line, error.column), (2, Some(2)));
+    }
+
+    #[test]
+    fn partial_policy_accepts_trailing_input() {
+        assert_eq!(Policy::strict().partial().parse("1\n2 x", numbers).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn trim_policy_ignores_surrounding_whitespace() {
+        assert!(Policy::strict().parse(" 1\n2\n", numbers).is_err());
+        assert_eq!(Policy::strict().trim().parse(" 1\n2\n", numbers).unwrap(), vec![1, 2]);
+
+        let error = Policy::strict().trim().parse("  1 x", numbers).unwrap_err();
+        assert_eq!(error.column, Some(4));
+        assert_eq!(error.snippet.as_deref(), Some("  1 x"));
+
+        let error = Policy::strict().trim().parse("\n\n 1\n2 x\n", numbers).unwrap_err();
+        assert_eq!((error.line, error.column), (4, Some(2)));
+    }
+
+    #[test]
+    fn trimmed_multibyte_whitespace_keeps_positions() {
+        let error = Policy::strict().trim().parse("1 x\u{3000}", numbers).unwrap_err();
+        assert_eq!((error.line, error.column), (1, Some(2)));
+
+        let error = Policy::strict().trim().parse("\u{3000}1 x\u{3000}", numbers).unwrap_err();
+        assert_eq!((error.line, error.column), (1, Some(3)));
+    }
+
+    #[test]
+    fn crlf_policy_normalizes_line_endings() {
+        let input = "1\r\n2\r\n3";
+        assert!(Policy::strict().parse(input, numbers).is_err());
+        assert_eq!(Policy::strict().crlf().parse(input, numbers).unwrap(), vec![1, 2, 3]);
+
+        let error = Policy::strict().crlf().trim().parse("1\r\n2\r\nx\r\n", numbers).unwrap_err();
+        assert_eq!((error.line, error.column), (2, Some(2)));
+    }
+}
@@ -0,0 +1,371 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::fetch::Client;
+
+pub const MIN_INTERVAL_SECS: u64 = 60;
+
+pub fn history_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("submissions.toml")
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited { wait_secs: u64 },
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn parse(html: &str) -> Self {
+        if html.contains("That's the right answer") {
+            return Verdict::Right;
+        }
+
+        if html.contains("You gave an answer too recently") {
+            return Verdict::RateLimited { wait_secs: parse_wait(html).unwrap_or(MIN_INTERVAL_SECS) };
+        }
+
+        if html.contains("You don't seem to be solving the right level") {
+            return Verdict::AlreadySolved;
+        }
+
+        if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                return Verdict::TooHigh;
+            }
+            if html.contains("your answer is too low") {
+                return Verdict::TooLow;
+            }
+            return Verdict::Wrong;
+        }
+
+        Verdict::Unknown
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::RateLimited { .. } => "rate-limited",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    fn from_key(key: &str, wait_secs: u64) -> Option<Self> {
+        let verdict = match key {
+            "right" => Verdict::Right,
+            "wrong" => Verdict::Wrong,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "rate-limited" => Verdict::RateLimited { wait_secs },
+            "already-solved" => Verdict::AlreadySolved,
+            "unknown" => Verdict::Unknown,
+            _ => return None,
+        };
+        Some(verdict)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::RateLimited { wait_secs } => write!(f, "rate-limited, wait {}s", wait_secs),
+            _ => f.write_str(self.key()),
+        }
+    }
+}
+
+// The server answers with e.g. "You have 1m 23s left to wait" or "You have 38s left to wait".
+fn parse_wait(html: &str) -> Option<u64> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    let mut secs: u64 = 0;
+    for token in html[start..end].split_whitespace() {
+        let unit = token.chars().last()?;
+        let value: u64 = token.strip_suffix(unit)?.parse().ok()?;
+        let scale = match unit {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        secs = value.checked_mul(scale).and_then(|v| secs.checked_add(v))?;
+    }
+
+    Some(secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub time: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => History::parse(&text).with_context(|| format!("error parsing {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e).with_context(|| format!("error reading {}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let table = text.parse::<toml::Table>()?;
+        let mut history = History::default();
+
+        let entries = match table.get("submission") {
+            Some(entries) => entries.as_array().context("`submission` must be an array of tables")?,
+            None => return Ok(history),
+        };
+
+        for entry in entries {
+            let int = |key: &str| {
+                entry.get(key).and_then(|v| v.as_integer()).ok_or_else(|| anyhow!("submission without `{}`", key))
+            };
+            let string = |key: &str| {
+                entry.get(key).and_then(|v| v.as_str()).ok_or_else(|| anyhow!("submission without `{}`", key))
+            };
+
+            let wait_secs = entry.get("wait").and_then(|v| v.as_integer()).unwrap_or(0) as u64;
+            let verdict = string("verdict")?;
+            let verdict = Verdict::from_key(verdict, wait_secs).with_context(|| format!("unknown verdict `{}`", verdict))?;
+
+            history.submissions.push(Submission {
+                day: int("day")? as usize,
+                part: int("part")? as usize,
+                answer: string("answer")?.to_string(),
+                verdict,
+                time: int("time")? as u64,
+            });
+        }
+
+        Ok(history)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string()).with_context(|| format!("error writing {}", path.display()))
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    // Reasons not to send this answer at all, judged only from earlier verdicts and timestamps.
+    pub fn check(&self, day: usize, part: usize, answer: &str, now: u64) -> Result<()> {
+        let previous = self.submissions.iter().filter(|s| s.day == day && s.part == part);
+
+        for s in previous {
+            match s.verdict {
+                Verdict::Right | Verdict::AlreadySolved => bail!("day {:02} part {} is already solved", day, part),
+                _ if s.answer == answer && s.verdict.is_wrong() => {
+                    bail!("`{}` was already submitted and is {}", answer, s.verdict)
+                }
+                Verdict::TooHigh if is_at_least(answer, &s.answer) => {
+                    bail!("`{}` is not below `{}`, which is too high", answer, s.answer)
+                }
+                Verdict::TooLow if is_at_least(&s.answer, answer) => {
+                    bail!("`{}` is not above `{}`, which is too low", answer, s.answer)
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(last) = self.submissions.iter().max_by_key(|s| s.time) {
+            let wait = match last.verdict {
+                Verdict::RateLimited { wait_secs } => wait_secs,
+                _ => MIN_INTERVAL_SECS,
+            };
+
+            let ready = last.time + wait;
+            if now < ready {
+                bail!("throttled, wait another {}s before submitting", ready - now);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_at_least(a: &str, b: &str) -> bool {
+    match (a.parse::<i64>(), b.parse::<i64>()) {
+        (Ok(a), Ok(b)) => a >= b,
+        _ => false,
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, s) in self.submissions.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[[submission]]")?;
+            writeln!(f, "day = {}", s.day)?;
+            writeln!(f, "part = {}", s.part)?;
+            writeln!(f, "answer = {:?}", s.answer)?;
+            writeln!(f, "verdict = {:?}", s.verdict.key())?;
+            if let Verdict::RateLimited { wait_secs } = s.verdict {
+                writeln!(f, "wait = {}", wait_secs)?;
+            }
+            writeln!(f, "time = {}", s.time)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn submit(client: &Client, history: &mut History, day: usize, part: usize, answer: &str, now: u64) -> Result<Verdict> {
+    history.check(day, part, answer, now)?;
+
+    let response = client.submit_answer(day, part, answer)?;
+    let verdict = Verdict::parse(&response);
+    history.record(Submission { day, part, answer: answer.to_string(), verdict, time: now });
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::stub::serve;
+
+    fn submission(part: usize, answer: &str, verdict: Verdict, time: u64) -> Submission {
+        Submission { day: 1, part, answer: answer.to_string(), verdict, time }
+    }
+
+    #[test]
+    fn verdicts_are_read_from_the_response() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+
+        assert_eq!(Verdict::parse(&page("That's the right answer! You are one gold star closer.")), Verdict::Right);
+        assert_eq!(Verdict::parse(&page("That's not the right answer; your answer is too high.")), Verdict::TooHigh);
+        assert_eq!(Verdict::parse(&page("That's not the right answer; your answer is too low.")), Verdict::TooLow);
+        assert_eq!(Verdict::parse(&page("That's not the right answer. If you're stuck...")), Verdict::Wrong);
+        assert_eq!(Verdict::parse(&page("You don't seem to be solving the right level.")), Verdict::AlreadySolved);
+        assert_eq!(Verdict::parse(&page("Something else")), Verdict::Unknown);
+
+        let limited = page("You gave an answer too recently. You have 1m 23s left to wait.");
+        assert_eq!(Verdict::parse(&limited), Verdict::RateLimited { wait_secs: 83 });
+    }
+
+    #[test]
+    fn odd_waits_fall_back_to_the_minimum_interval() {
+        let limited = |wait: &str| Verdict::parse(&format!("You gave an answer too recently. You have {} left to wait", wait));
+        let minimum = Verdict::RateLimited { wait_secs: MIN_INTERVAL_SECS };
+
+        assert_eq!(limited("1é"), minimum);
+        assert_eq!(limited("é"), minimum);
+        assert_eq!(limited("18446744073709551615h"), minimum);
+        assert_eq!(limited("18446744073709551615s 1s"), minimum);
+        assert_eq!(limited("1h 2s"), Verdict::RateLimited { wait_secs: 3602 });
+    }
+
+    #[test]
+    fn history_round_trips_through_toml() {
+        let mut history = History::default();
+        history.record(submission(1, "12", Verdict::TooLow, 100));
+        history.record(submission(1, "ABC", Verdict::RateLimited { wait_secs: 30 }, 200));
+
+        assert_eq!(History::parse(&history.to_string()).unwrap(), history);
+    }
+
+    #[test]
+    fn known_wrong_answers_are_not_resubmitted() {
+        let mut history = History::default();
+        history.record(submission(1, "100", Verdict::TooHigh, 0));
+        history.record(submission(1, "10", Verdict::TooLow, 100));
+        history.record(submission(1, "55", Verdict::Wrong, 200));
+
+        assert!(history.check(1, 1, "55", 1000).is_err());
+        assert!(history.check(1, 1, "100", 1000).is_err());
+        assert!(history.check(1, 1, "150", 1000).is_err());
+        assert!(history.check(1, 1, "5", 1000).is_err());
+        assert!(history.check(1, 1, "50", 1000).is_ok());
+        assert!(history.check(1, 2, "55", 1000).is_ok());
+    }
+
+    #[test]
+    fn solved_parts_are_not_resubmitted() {
+        let mut history = History::default();
+        history.record(submission(1, "42", Verdict::Right, 0));
+
+        assert!(history.check(1, 1, "43", 1000).is_err());
+        assert!(history.check(1, 2, "43", 1000).is_ok());
+    }
+
+    #[test]
+    fn submissions_are_throttled() {
+        let mut history = History::default();
+        history.record(submission(1, "1", Verdict::Wrong, 1000));
+        assert!(history.check(1, 1, "2", 1000 + MIN_INTERVAL_SECS - 1).is_err());
+        assert!(history.check(1, 1, "2", 1000 + MIN_INTERVAL_SECS).is_ok());
+
+        history.record(submission(1, "2", Verdict::RateLimited { wait_secs: 300 }, 2000));
+        assert!(history.check(1, 1, "3", 2299).is_err());
+        assert!(history.check(1, 1, "3", 2300).is_ok());
+    }
+
+    #[test]
+    fn submitting_posts_the_answer_and_records_the_verdict() {
+        let (url, requests) = serve(vec![(200, "<article><p>That's the right answer!</p></article>")]);
+        let client = Client::new(&url, "secret");
+        let mut history = History::default();
+
+        let verdict = submit(&client, &mut history, 5, 2, "MCD", 1000).unwrap();
+        assert_eq!(verdict, Verdict::Right);
+        assert_eq!(history.submissions(), &[Submission { day: 5, part: 2, answer: "MCD".to_string(), verdict, time: 1000 }]);
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2022/day/5/answer HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+        assert!(request.ends_with("level=2&answer=MCD"));
+    }
+
+    #[test]
+    fn rejected_submissions_never_reach_the_server() {
+        let mut history = History::default();
+        history.record(submission(1, "7", Verdict::Wrong, 0));
+
+        // nothing listens on this address, so any request would fail differently
+        let client = Client::new("http://127.0.0.1:9", "secret");
+        let error = submit(&client, &mut history, 1, 1, "7", 1000).unwrap_err();
+        assert!(error.to_string().contains("already submitted"));
+        assert_eq!(history.submissions().len(), 1);
+    }
+}
@@ -0,0 +1,152 @@
+use std::{fmt::Debug, str::FromStr};
+
+use crate::answers::Answers;
+use crate::extract::{examples_path, EXAMPLE_VARIANT};
+use crate::{InputSource, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Answer(String),
+    Error(&'static str),
+}
+
+pub fn check_example<S: Solution>(name: &str, input: &str, expected: Expected) {
+    let lines = input.lines().map(String::from).collect();
+    let result = S::parse(lines).and_then(|i| S::solve(&i)).map(|o| o.into().to_string());
+    let context = format!("example `{}` of day {:02} part {}", name, S::DAY, S::PART);
+
+    match (result, expected) {
+        (Ok(answer), Expected::Answer(expected)) => {
+            assert!(answer == expected, "{}: expected `{}`, got `{}`\ninput:\n{}", context, expected, answer, input);
+        }
+        (Err(e), Expected::Error(message)) => {
+            let error = format!("{:#}", e);
+            assert!(error.contains(message), "{}: expected error containing `{}`, got `{}`", context, message, error);
+        }
+        (Ok(answer), Expected::Error(message)) => {
+            panic!("{}: expected error containing `{}`, got answer `{}`\ninput:\n{}", context, message, answer, input);
+        }
+        (Err(e), Expected::Answer(expected)) => {
+            panic!("{}: expected `{}`, got error `{:#}`\ninput:\n{}", context, expected, e, input);
+        }
+    }
+}
+
+// The example input written by `aoc extract`.
+pub fn saved_example(day: usize) -> Vec<String> {
+    match InputSource::variant(day, EXAMPLE_VARIANT).read() {
+        Ok(lines) => lines,
+        Err(e) => panic!("error reading saved example of day {:02}: {}", day, e),
+    }
+}
+
+// Checks the example written by `aoc extract` against its recorded answer.
+pub fn check_saved_example<S: Solution>() {
+    let input = saved_example(S::DAY).join("\n");
+
+    let answers = Answers::load(&examples_path()).unwrap_or_else(|e| panic!("{:#}", e));
+    let expected = answers
+        .get(S::DAY, S::PART)
+        .unwrap_or_else(|| panic!("no example answer saved for day {:02} part {}", S::DAY, S::PART));
+
+    check_example::<S>("saved", &input, Expected::Answer(expected.to_string()));
+}
+
+pub fn parse_lines<T>(lines: &[&str]) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| match l.parse() {
+            Ok(v) => v,
+            Err(e) => panic!("error parsing line {} `{}`: {:?}", i + 1, l, e),
+        })
+        .collect()
+}
+
+/// Expands puzzle examples into one `#[test]` per example, grouped in a module per part.
+///
+/// ```ignore
+/// examples! {
+///     part1: Part1 {
+///         first: "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => 7,
+///         too_short: "abc" => error("not enough characters"),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($($module:ident: $solution:ty { $($body:tt)* })*) => {
+        $(
+            mod $module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $crate::examples!(@item $solution; $($body)*);
+            }
+        )*
+    };
+
+    (@item $solution:ty;) => {};
+
+    (@item $solution:ty; $name:ident: $input:expr => error($message:expr) $(, $($rest:tt)*)?) => {
+        #[test]
+        fn $name() {
+            let expected = $crate::testing::Expected::Error($message);
+            $crate::testing::check_example::<$solution>(stringify!($name), $input, expected);
+        }
+
+        $crate::examples!(@item $solution; $($($rest)*)?);
+    };
+
+    (@item $solution:ty; $name:ident: $input:expr => $answer:expr $(, $($rest:tt)*)?) => {
+        #[test]
+        fn $name() {
+            let expected = $crate::testing::Expected::Answer($answer.to_string());
+            $crate::testing::check_example::<$solution>(stringify!($name), $input, expected);
+        }
+
+        $crate::examples!(@item $solution; $($($rest)*)?);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::Part1;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    crate::examples! {
+        generated: Part1 {
+            first: EXAMPLE => 24000,
+            broken: "1000\nabc" => error("invalid digit"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "example `wrong` of day 01 part 1: expected `1`, got `24000`")]
+    fn mismatches_name_the_example() {
+        check_example::<Part1>("wrong", EXAMPLE, Expected::Answer("1".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected error containing `boom`, got answer `24000`")]
+    fn unexpected_answers_fail() {
+        check_example::<Part1>("wrong", EXAMPLE, Expected::Error("boom"));
+    }
+
+    #[test]
+    fn lines_are_parsed_in_order() {
+        assert_eq!(parse_lines::<u32>(&["1", "2"]), vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "error parsing line 2 `x`")]
+    fn unparsable_lines_name_the_line() {
+        parse_lines::<u32>(&["1", "x"]);
+    }
+}
@@ -0,0 +1,129 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+// A value together with its position in the input it was selected from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ranked<T> {
+    pub index: usize,
+    pub value: T,
+}
+
+// Larger values rank higher, and among equal values the one seen first.
+impl<T: Ord> Ord for Ranked<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl<T: Ord> PartialOrd for Ranked<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Keeps the `k` highest ranked values seen so far in a min-heap of at most `k` entries, so that
+// selecting from `n` values takes O(n log k) time and O(k) space.
+#[derive(Debug, Clone)]
+pub struct TopK<T> {
+    k: usize,
+    seen: usize,
+    heap: BinaryHeap<Reverse<Ranked<T>>>,
+}
+
+impl<T: Ord> TopK<T> {
+    // `k` may well exceed the number of values, `usize::MAX` keeps all of them.
+    pub fn new(k: usize) -> Self {
+        TopK { k, seen: 0, heap: BinaryHeap::with_capacity(k.saturating_add(1).min(1024)) }
+    }
+
+    // Values are indexed in the order they are pushed.
+    pub fn push(&mut self, value: T) {
+        let ranked = Ranked { index: self.seen, value };
+        self.seen += 1;
+
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(ranked));
+        } else if self.heap.peek().is_some_and(|Reverse(lowest)| ranked > *lowest) {
+            self.heap.pop();
+            self.heap.push(Reverse(ranked));
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    // Highest ranked first.
+    pub fn into_sorted_vec(self) -> Vec<Ranked<T>> {
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(r)| r).collect()
+    }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+pub fn top_k<T: Ord>(values: impl IntoIterator<Item = T>, k: usize) -> Vec<Ranked<T>> {
+    let mut top = TopK::new(k);
+    top.extend(values);
+    top.into_sorted_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn indices<T>(ranked: &[Ranked<T>]) -> Vec<usize> {
+        ranked.iter().map(|r| r.index).collect()
+    }
+
+    #[test]
+    fn selects_the_highest_values_in_order() {
+        let top = top_k([6000, 4000, 11000, 24000, 10000], 3);
+
+        assert_eq!(top.iter().map(|r| r.value).collect::<Vec<_>>(), vec![24000, 11000, 10000]);
+        assert_eq!(indices(&top), vec![3, 2, 4]);
+    }
+
+    #[test]
+    fn ties_keep_the_earlier_values() {
+        let top = top_k([1, 3, 2, 3, 3], 2);
+        assert_eq!(indices(&top), vec![1, 3]);
+    }
+
+    #[test]
+    fn short_and_empty_inputs() {
+        assert_eq!(top_k([5, 7], 3).len(), 2);
+        assert!(top_k(Vec::<i32>::new(), 3).is_empty());
+        assert!(top_k([1, 2, 3], 0).is_empty());
+    }
+
+    #[test]
+    fn unbounded_k_keeps_everything() {
+        let top = top_k([2, 9, 4], usize::MAX);
+        assert_eq!(top.iter().map(|r| r.value).collect::<Vec<_>>(), vec![9, 4, 2]);
+        assert_eq!(indices(&top), vec![1, 2, 0]);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_a_stable_sort(values in prop::collection::vec(0..10i32, 0..30), k in 0..8usize) {
+            let mut sorted = values.iter().copied().enumerate().collect::<Vec<_>>();
+            sorted.sort_by_key(|&(_, v)| Reverse(v));
+            sorted.truncate(k);
+
+            let top = top_k(values, k).into_iter().map(|r| (r.index, r.value)).collect::<Vec<_>>();
+            prop_assert_eq!(top, sorted);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::input::input_dir;
+
+pub type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+// The day's source plus every input variant of it, e.g. `day05.txt` and `day05.example.txt`.
+pub fn watched_files(day: usize) -> Vec<PathBuf> {
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("day{:02}.rs", day));
+    let prefix = format!("day{:02}.", day);
+
+    let mut files = vec![source];
+    if let Ok(entries) = fs::read_dir(input_dir()) {
+        let inputs = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.starts_with(&prefix)));
+        files.extend(inputs);
+    }
+
+    files
+}
+
+pub fn snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .filter_map(|f| Some((f.clone(), fs::metadata(f).and_then(|m| m.modified()).ok()?)))
+        .collect()
+}
+
+// Files that were added, removed or modified between two snapshots.
+pub fn changes(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let modified = after.iter().filter(|(f, t)| before.get(*f) != Some(t)).map(|(f, _)| f);
+    let removed = before.keys().filter(|f| !after.contains_key(*f));
+
+    let mut changed = modified.chain(removed).cloned().collect::<Vec<_>>();
+    changed.sort();
+    changed
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Outcome {
+    pub answers: BTreeMap<usize, Result<String, String>>,
+    pub tests: Option<TestSummary>,
+}
+
+// Reads the parts of `aoc run --format json-lines`.
+pub fn parse_answers(output: &str) -> Result<BTreeMap<usize, Result<String, String>>> {
+    let mut answers = BTreeMap::new();
+
+    for line in output.lines().filter(|l| !l.trim().is_empty()) {
+        let record: serde_json::Value = serde_json::from_str(line).with_context(|| format!("invalid record `{}`", line))?;
+        let part = record["part"].as_u64().context("record without a part")? as usize;
+
+        let answer = match (&record["answer"], &record["error"]) {
+            (serde_json::Value::String(s), _) => Ok(s.clone()),
+            (serde_json::Value::Number(n), _) => Ok(n.to_string()),
+            (_, serde_json::Value::String(e)) => Err(e.clone()),
+            _ => bail!("record without an answer or error `{}`", line),
+        };
+        answers.insert(part, answer);
+    }
+
+    Ok(answers)
+}
+
+// Adds up the `test result: ok. 4 passed; 0 failed; ...` lines of libtest.
+pub fn parse_test_summary(output: &str) -> Option<TestSummary> {
+    let mut summary = None;
+
+    for line in output.lines().filter_map(|l| l.trim().strip_prefix("test result: ")) {
+        let count = |label: &str| {
+            line.split([';', '.'])
+                .filter_map(|field| field.trim().strip_suffix(label))
+                .find_map(|n| n.trim().parse::<usize>().ok())
+                .unwrap_or(0)
+        };
+
+        let total = summary.get_or_insert(TestSummary { passed: 0, failed: 0 });
+        total.passed += count(" passed");
+        total.failed += count(" failed");
+    }
+
+    summary
+}
+
+pub fn diff(day: usize, previous: Option<&Outcome>, current: &Outcome) -> Vec<String> {
+    let mut lines = Vec::new();
+    let show = |answer: &Result<String, String>| match answer {
+        Ok(a) => a.clone(),
+        Err(e) => format!("error: {}", e),
+    };
+
+    for (part, answer) in &current.answers {
+        let label = format!("day {:02} part {}", day, part);
+        match previous.and_then(|p| p.answers.get(part)) {
+            None => lines.push(format!("{}: {}", label, show(answer))),
+            Some(before) if before == answer => lines.push(format!("{}: {} (unchanged)", label, show(answer))),
+            Some(before) => lines.push(format!("{}: {} -> {}", label, show(before), show(answer))),
+        }
+    }
+
+    if let Some(tests) = current.tests {
+        let line = format!("tests: {} passed, {} failed", tests.passed, tests.failed);
+        match previous.and_then(|p| p.tests) {
+            Some(before) if before != tests => {
+                lines.push(format!("{} (was {} passed, {} failed)", line, before.passed, before.failed))
+            }
+            _ => lines.push(line),
+        }
+    }
+
+    lines
+}
+
+// Source changes need a rebuild, so the day runs through cargo; a separate target directory keeps
+// the rebuild from fighting over the binary that is watching.
+pub fn evaluate(day: usize) -> Result<Outcome> {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let target_dir = Path::new(manifest_dir).join("target").join("watch");
+    let cargo = || {
+        let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+        command.current_dir(manifest_dir).arg("--quiet");
+        command
+    };
+
+    let run = cargo()
+        .args(["run", "--bin", "aoc", "--target-dir"])
+        .arg(&target_dir)
+        .args(["--", "run", &day.to_string(), "--format", "json-lines"])
+        .output()
+        .context("error running cargo")?;
+
+    let stdout = String::from_utf8_lossy(&run.stdout);
+    let answers = parse_answers(&stdout)?;
+    if answers.is_empty() && !run.status.success() {
+        bail!("{}", String::from_utf8_lossy(&run.stderr).trim());
+    }
+
+    let test = cargo()
+        .args(["test", "--lib", "--target-dir"])
+        .arg(&target_dir)
+        .args(["--", &format!("day{:02}::", day)])
+        .output()
+        .context("error running cargo")?;
+
+    Ok(Outcome { answers, tests: parse_test_summary(&String::from_utf8_lossy(&test.stdout)) })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn outcome(answers: &[(usize, Result<&str, &str>)], tests: Option<(usize, usize)>) -> Outcome {
+        Outcome {
+            answers: answers.iter().map(|(p, a)| (*p, a.map(String::from).map_err(String::from))).collect(),
+            tests: tests.map(|(passed, failed)| TestSummary { passed, failed }),
+        }
+    }
+
+    #[test]
+    fn changes_cover_added_removed_and_modified_files() {
+        let t = |s| SystemTime::UNIX_EPOCH + Duration::from_secs(s);
+        let before = Snapshot::from([("a".into(), t(1)), ("b".into(), t(1)), ("c".into(), t(1))]);
+        let after = Snapshot::from([("a".into(), t(1)), ("b".into(), t(2)), ("d".into(), t(1))]);
+
+        let changed = changes(&before, &after);
+        assert_eq!(changed, vec![PathBuf::from("b"), PathBuf::from("c"), PathBuf::from("d")]);
+        assert!(changes(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn answers_are_read_from_json_lines() {
+        let output = concat!(
+            r#"{"day":5,"part":1,"status":"ok","answer":"CMZ","timings":{"parse_ns":1,"solve_ns":2}}"#,
+            "\n",
+            r#"{"day":5,"part":2,"status":"failed","error":"boom","timings":{"parse_ns":0,"solve_ns":0}}"#,
+            "\n",
+        );
+
+        let answers = parse_answers(output).unwrap();
+        assert_eq!(answers[&1], Ok("CMZ".to_string()));
+        assert_eq!(answers[&2], Err("boom".to_string()));
+        assert_eq!(parse_answers(r#"{"part":1,"answer":24000}"#).unwrap()[&1], Ok("24000".to_string()));
+        assert!(parse_answers("not json").is_err());
+    }
+
+    #[test]
+    fn test_summaries_are_added_up() {
+        let output = "running 4 tests\n....\ntest result: ok. 4 passed; 0 failed; 0 ignored; 0 measured; 101 filtered out; finished in 0.00s\n\
+                      test result: FAILED. 1 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s\n";
+        assert_eq!(parse_test_summary(output), Some(TestSummary { passed: 5, failed: 2 }));
+        assert_eq!(parse_test_summary("error: could not compile"), None);
+    }
+
+    #[test]
+    fn diffs_show_what_changed() {
+        let before = outcome(&[(1, Ok("24000")), (2, Err("boom"))], Some((3, 1)));
+        let after = outcome(&[(1, Ok("24000")), (2, Ok("45000"))], Some((4, 0)));
+
+        assert_eq!(
+            diff(1, Some(&before), &after),
+            vec![
+                "day 01 part 1: 24000 (unchanged)",
+                "day 01 part 2: error: boom -> 45000",
+                "tests: 4 passed, 0 failed (was 3 passed, 1 failed)",
+            ]
+        );
+        assert_eq!(diff(1, None, &after)[0], "day 01 part 1: 24000");
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::iter::Copied;
+use std::slice;
+use std::str::Chars;
+
+// How often every symbol occurs in the current window.
+pub trait Counts<T> {
+    // Both return the count of `item` after the change.
+    fn add(&mut self, item: &T) -> usize;
+    fn remove(&mut self, item: &T) -> usize;
+}
+
+pub struct ByteCounts([usize; 256]);
+
+impl Default for ByteCounts {
+    fn default() -> Self {
+        ByteCounts([0; 256])
+    }
+}
+
+impl Counts<u8> for ByteCounts {
+    fn add(&mut self, item: &u8) -> usize {
+        self.0[*item as usize] += 1;
+        self.0[*item as usize]
+    }
+
+    fn remove(&mut self, item: &u8) -> usize {
+        self.0[*item as usize] -= 1;
+        self.0[*item as usize]
+    }
+}
+
+impl<T: Hash + Eq + Clone> Counts<T> for HashMap<T, usize> {
+    fn add(&mut self, item: &T) -> usize {
+        let count = self.entry(item.clone()).or_default();
+        *count += 1;
+        *count
+    }
+
+    fn remove(&mut self, item: &T) -> usize {
+        let count = self.get_mut(item).map_or(0, |c| {
+            *c -= 1;
+            *c
+        });
+        if count == 0 {
+            self.remove(item);
+        }
+        count
+    }
+}
+
+// Yields the end of every window of `length` symbols that are all different, i.e. the number of
+// symbols read up to and including the window. Every symbol is looked at twice, whatever the length.
+pub struct DistinctWindows<I: Iterator, C> {
+    items: I,
+    length: usize,
+    window: VecDeque<I::Item>,
+    counts: C,
+    duplicates: usize,
+    position: usize,
+}
+
+impl<I: Iterator, C: Counts<I::Item> + Default> DistinctWindows<I, C> {
+    pub fn new(items: I, length: usize) -> Self {
+        assert!(length > 0, "windows need at least one symbol");
+        let window = VecDeque::with_capacity(length + 1);
+        DistinctWindows { items, length, window, counts: C::default(), duplicates: 0, position: 0 }
+    }
+}
+
+impl<I: Iterator, C: Counts<I::Item>> Iterator for DistinctWindows<I, C> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let item = self.items.next()?;
+            self.position += 1;
+
+            if self.counts.add(&item) > 1 {
+                self.duplicates += 1;
+            }
+            self.window.push_back(item);
+
+            if self.window.len() > self.length {
+                let old = self.window.pop_front()?;
+                if self.counts.remove(&old) > 0 {
+                    self.duplicates -= 1;
+                }
+            }
+
+            if self.window.len() == self.length && self.duplicates == 0 {
+                return Some(self.position);
+            }
+        }
+    }
+}
+
+pub fn distinct_windows<I>(items: I, length: usize) -> DistinctWindows<I::IntoIter, HashMap<I::Item, usize>>
+where
+    I: IntoIterator,
+    I::Item: Hash + Eq + Clone,
+{
+    DistinctWindows::new(items.into_iter(), length)
+}
+
+pub fn distinct_byte_windows(bytes: &[u8], length: usize) -> DistinctWindows<Copied<slice::Iter<'_, u8>>, ByteCounts> {
+    DistinctWindows::new(bytes.iter().copied(), length)
+}
+
+// Positions count chars, not bytes.
+pub fn distinct_char_windows(s: &str, length: usize) -> DistinctWindows<Chars<'_>, HashMap<char, usize>> {
+    DistinctWindows::new(s.chars(), length)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn naive(items: &[u8], length: usize) -> Vec<usize> {
+        let distinct = |w: &[u8]| w.iter().collect::<HashSet<_>>().len() == w.len();
+        items.windows(length).enumerate().filter(|(_, w)| distinct(w)).map(|(i, _)| i + length).collect()
+    }
+
+    #[test]
+    fn finds_the_first_and_every_window() {
+        let bytes = b"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+        assert_eq!(distinct_byte_windows(bytes, 4).next(), Some(7));
+        assert_eq!(distinct_byte_windows(bytes, 14).next(), Some(19));
+        assert_eq!(distinct_byte_windows(b"abcabd", 3).collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+        assert_eq!(distinct_byte_windows(b"aabb", 2).collect::<Vec<_>>(), vec![3]);
+        assert_eq!(distinct_byte_windows(b"ab", 3).next(), None);
+    }
+
+    #[test]
+    fn works_on_chars_and_any_hashable_items() {
+        assert_eq!(distinct_char_windows("ééaéb", 2).collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(distinct_windows([1, 1, 2, 3, 3], 3).collect::<Vec<_>>(), vec![4]);
+        assert_eq!(distinct_windows(["x", "y", "x"], 2).count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_are_not_empty() {
+        distinct_byte_windows(b"abc", 0);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_checking_every_window(items in prop::collection::vec(0..6u8, 0..40), length in 1..6usize) {
+            prop_assert_eq!(distinct_byte_windows(&items, length).collect::<Vec<_>>(), naive(&items, length));
+            prop_assert_eq!(distinct_windows(items.iter(), length).collect::<Vec<_>>(), naive(&items, length));
+        }
+    }
+}